@@ -0,0 +1,317 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, RwLock};
+use std::thread;
+
+use crate::world::manager::{BuildingType, NpcRace, NpcSex};
+use crate::world::time::Phase;
+use crate::world::weather::WeatherType;
+
+// Struct for Dialogue Manager
+pub struct DialogueManager {
+    provider: Arc<dyn super::provider::DialogueProvider>,
+    budget: super::memory::MemoryBudget,
+    pub npc_id: Option<u32>,
+    new_conversation: bool,
+    pub memories: HashMap<u32, super::memory::DialogueMemory>,
+    pub error: Option<String>,
+    config_error: Option<String>,
+    pub generation_arc_rwlock: Option<Arc<RwLock<Generation>>>,
+    cancel_flag: Arc<AtomicBool>,
+}
+
+// Functions for Dialogue Manager
+impl DialogueManager {
+    // Create a new Dialogue Manager using the provider from the config
+    pub fn new(config: &crate::core::config::Config) -> Self {
+        Self {
+            provider: super::provider::from_config(config),
+            budget: super::memory::MemoryBudget {
+                max_chars: config.dialogue_memory_chars,
+                keep_recent: config.dialogue_keep_recent,
+            },
+            npc_id: None,
+            new_conversation: false,
+            memories: HashMap::new(),
+            error: None,
+            config_error: config_error(config),
+            generation_arc_rwlock: None,
+            cancel_flag: Arc::new(AtomicBool::new(false)),
+        }
+    }
+
+    // Clear all conversations and memories
+    pub fn clear(&mut self) {
+        self.cancel();
+        self.npc_id = None;
+        self.memories.clear();
+        self.error = None;
+    }
+
+    // Start a conversation with an NPC
+    pub fn begin(&mut self, npc_id: u32) {
+        self.npc_id = Some(npc_id);
+        self.new_conversation = true;
+        self.error = self.config_error.clone();
+    }
+
+    // End the current conversation
+    pub fn end(&mut self) {
+        self.cancel();
+        self.npc_id = None;
+        self.error = None;
+    }
+
+    // Get the memory of the NPC currently being talked to
+    pub fn current_memory(&self) -> Option<&super::memory::DialogueMemory> {
+        self.memories.get(&self.npc_id?)
+    }
+
+    // Check if the NPC is still working on a reply
+    pub fn is_generating(&self) -> bool {
+        self.generation_arc_rwlock.is_some()
+    }
+
+    // Send the player's message, the reply is generated in its own thread
+    pub fn send(
+        &mut self,
+        mut context: DialogueContext,
+        message: String,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        if self.is_generating() {
+            return Err("Still waiting on the last reply.".into());
+        }
+
+        context.new_conversation = self.new_conversation;
+
+        let npc_id = self.npc_id.ok_or("No NPC to talk to.")?;
+        let memory = self.memories.entry(npc_id).or_default();
+
+        memory.transcript.push(DialogueLine {
+            speaker: Speaker::Player,
+            text: message,
+        });
+
+        // The thread works on its own copy of the memory and hands it back when done
+        let mut memory = memory.clone();
+        let provider = Arc::clone(&self.provider);
+        let budget = self.budget.clone();
+
+        let generation = Arc::new(RwLock::new(Generation::new(npc_id)));
+        let generation_arc_clone = Arc::clone(&generation);
+
+        // Each generation gets its own flag so a cancelled thread can't be revived
+        self.cancel_flag = Arc::new(AtomicBool::new(false));
+        let cancel_flag_arc_clone = Arc::clone(&self.cancel_flag);
+
+        thread::spawn(move || {
+            let result = provider.stream_reply(&context, &memory, &mut |token| {
+                if cancel_flag_arc_clone.load(Ordering::Relaxed) {
+                    return false;
+                }
+
+                if let Ok(mut generation) = generation_arc_clone.write() {
+                    generation.text.push_str(token);
+                }
+
+                true
+            });
+
+            if cancel_flag_arc_clone.load(Ordering::Relaxed) {
+                return;
+            }
+
+            let status = match result {
+                Ok(reply) => {
+                    memory.transcript.push(DialogueLine {
+                        speaker: Speaker::Npc,
+                        text: reply,
+                    });
+
+                    // Fold older lines into the summary once memory grows past its budget
+                    if memory.over_budget(&budget) {
+                        let oldest = memory.take_oldest(&budget);
+
+                        match provider.summarize(&context, &memory.summary, &oldest) {
+                            Ok(summary) => memory.set_summary(summary, &budget),
+                            Err(e) => {
+                                log::error!("Failed to summarize dialogue memory: {}", e);
+
+                                // Put the lines back, we'll try again after the next reply
+                                memory.transcript.splice(0..0, oldest);
+                            }
+                        }
+                    }
+
+                    GenerationStatus::Done(memory)
+                }
+                Err(e) => GenerationStatus::Failed(e.to_string()),
+            };
+
+            if let Ok(mut generation) = generation_arc_clone.write() {
+                generation.status = status;
+            }
+        });
+
+        self.generation_arc_rwlock = Some(generation);
+
+        Ok(())
+    }
+
+    // Check on the generation thread and take its result once it has finished
+    pub fn update(&mut self) {
+        let Some(generation) = self.generation_arc_rwlock.as_ref() else {
+            return;
+        };
+
+        let (npc_id, status) = match generation.write() {
+            Ok(mut generation) => match generation.status {
+                GenerationStatus::Streaming => return,
+                _ => (
+                    generation.npc_id,
+                    std::mem::replace(&mut generation.status, GenerationStatus::Streaming),
+                ),
+            },
+            Err(_) => {
+                log::error!("Failed to read Generation (lock poisoned?).");
+                (0, GenerationStatus::Failed("Generation unavailable".into()))
+            }
+        };
+
+        self.generation_arc_rwlock = None;
+
+        match status {
+            GenerationStatus::Done(memory) => {
+                self.memories.insert(npc_id, memory);
+                self.new_conversation = false;
+                self.error = None;
+            }
+            GenerationStatus::Failed(e) => {
+                log::error!("Failed to get NPC reply: {}", e);
+
+                self.take_back_message(npc_id);
+                self.error = Some(
+                    self.config_error
+                        .clone()
+                        .unwrap_or_else(|| "They don't seem to hear you.".into()),
+                );
+            }
+            GenerationStatus::Streaming => {}
+        }
+    }
+
+    // Cancel the reply being generated, if any
+    pub fn cancel(&mut self) {
+        if let Some(generation) = self.generation_arc_rwlock.take() {
+            self.cancel_flag.store(true, Ordering::Relaxed);
+
+            if let Ok(generation) = generation.read() {
+                self.take_back_message(generation.npc_id);
+            }
+        }
+    }
+
+    // Remove the player's unanswered line so they can try again
+    fn take_back_message(&mut self, npc_id: u32) {
+        if let Some(memory) = self.memories.get_mut(&npc_id) {
+            if matches!(
+                memory.transcript.last(),
+                Some(DialogueLine {
+                    speaker: Speaker::Player,
+                    ..
+                })
+            ) {
+                memory.transcript.pop();
+            }
+        }
+    }
+}
+
+// A config problem that stops the HTTP provider working, shown to the player when they try to talk
+fn config_error(config: &crate::core::config::Config) -> Option<String> {
+    if !matches!(
+        config.dialogue_provider,
+        crate::core::config::ProviderType::Http
+    ) {
+        return None;
+    }
+
+    super::http::validate_url(&config.ai_url)
+        .err()
+        .map(|e| format!("Dialogue is unavailable: {}", e))
+}
+
+// Struct for a reply being generated, shared with the generation thread
+pub struct Generation {
+    npc_id: u32,
+    pub text: String,
+    status: GenerationStatus,
+}
+
+// Functions for Generation
+impl Generation {
+    // Create a new Generation for an NPC
+    fn new(npc_id: u32) -> Self {
+        Self {
+            npc_id,
+            text: String::new(),
+            status: GenerationStatus::Streaming,
+        }
+    }
+}
+
+// Enum for the state of a reply being generated
+enum GenerationStatus {
+    Streaming,
+    Done(super::memory::DialogueMemory),
+    Failed(String),
+}
+
+// Struct for everything an NPC knows about themselves and their surroundings
+pub struct DialogueContext {
+    pub npc_name: String,
+    pub npc_race: NpcRace,
+    pub npc_sex: NpcSex,
+    pub building_name: String,
+    pub building_type: BuildingType,
+    pub town_name: String,
+    pub phase: Phase,
+    pub weather: WeatherType,
+    pub new_conversation: bool,
+}
+
+// Functions for Dialogue Context
+impl DialogueContext {
+    // Build the system prompt describing the NPC for a language model
+    pub fn system_prompt(&self) -> String {
+        format!(
+            "You are {name}, a {sex:?} {race:?} in a fantasy role-playing game. \
+             You are in {building} (a {building_type:?}) in the town of {town}. \
+             It is currently {phase:?} and the weather is {weather:?}. \
+             Stay in character, speak naturally, and keep replies to one to three sentences.",
+            name = self.npc_name,
+            sex = self.npc_sex,
+            race = self.npc_race,
+            building = self.building_name,
+            building_type = self.building_type,
+            town = self.town_name,
+            phase = self.phase,
+            weather = self.weather,
+        )
+    }
+}
+
+// Struct for a single line of dialogue
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DialogueLine {
+    pub speaker: Speaker,
+    pub text: String,
+}
+
+// Enum for who said a line of dialogue
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum Speaker {
+    Player,
+    Npc,
+}
@@ -0,0 +1,401 @@
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{TcpStream, ToSocketAddrs};
+use std::time::Duration;
+
+// Struct for the parts of an http:// URL we need to make a request
+struct Url {
+    host: String,
+    port: u16,
+    path: String,
+}
+
+// Functions for Url
+impl Url {
+    // Parse a plain http:// URL, e.g. http://localhost:11434/v1/chat/completions or http://[::1]:8080/
+    fn parse(url: &str) -> Result<Self, Box<dyn std::error::Error>> {
+        if url.starts_with("https://") {
+            return Err(format!(
+                "HTTPS isn't supported, set ai_url to a local http:// endpoint (e.g. Ollama, or a proxy in front of a hosted API): {}",
+                url
+            )
+            .into());
+        }
+
+        let rest = url
+            .strip_prefix("http://")
+            .ok_or_else(|| format!("Unsupported URL (only http:// is supported): {}", url))?;
+
+        let (authority, path) = match rest.find('/') {
+            Some(index) => (&rest[..index], &rest[index..]),
+            None => (rest, "/"),
+        };
+
+        // IPv6 hosts are wrapped in brackets so their colons aren't mistaken for the port
+        let (host, port) = match authority.strip_prefix('[') {
+            Some(bracketed) => {
+                let (host, after) = bracketed
+                    .split_once(']')
+                    .ok_or_else(|| format!("Missing ] in URL: {}", url))?;
+
+                match after {
+                    "" => (host, 80),
+                    after => match after.strip_prefix(':') {
+                        Some(port) => (host, port.parse()?),
+                        None => return Err(format!("Malformed host in URL: {}", url).into()),
+                    },
+                }
+            }
+            None => match authority.rsplit_once(':') {
+                Some((host, port)) => (host, port.parse()?),
+                None => (authority, 80),
+            },
+        };
+
+        if host.is_empty() {
+            return Err(format!("Missing host in URL: {}", url).into());
+        }
+
+        Ok(Self {
+            host: host.to_string(),
+            port,
+            path: path.to_string(),
+        })
+    }
+
+    // The host and port as written in a Host header, with brackets back around IPv6 hosts
+    fn authority(&self) -> String {
+        if self.host.contains(':') {
+            format!("[{}]:{}", self.host, self.port)
+        } else {
+            format!("{}:{}", self.host, self.port)
+        }
+    }
+}
+
+// Check a URL can be used before any request is made, so a bad config is reported up front
+pub fn validate_url(url: &str) -> Result<(), Box<dyn std::error::Error>> {
+    Url::parse(url).map(|_| ())
+}
+
+// POST a JSON body and return the response body
+pub fn post_json(
+    url: &str,
+    body: &str,
+    api_key: Option<&str>,
+    timeout: Duration,
+) -> Result<String, Box<dyn std::error::Error>> {
+    let (status, mut response) = send(url, body, api_key, timeout)?;
+
+    // The status matters more than a short body, so check it before any read error
+    let mut response_body = Vec::new();
+    let read = response.read_to_end(&mut response_body);
+    let response_body = String::from_utf8_lossy(&response_body).to_string();
+
+    if !(200..300).contains(&status) {
+        return Err(format!("HTTP {}: {}", status, response_body).into());
+    }
+
+    read?;
+
+    Ok(response_body)
+}
+
+// POST a JSON body and pass each line of the response to on_line as it arrives
+// Stops reading early if on_line returns false
+pub fn post_json_lines(
+    url: &str,
+    body: &str,
+    api_key: Option<&str>,
+    timeout: Duration,
+    on_line: &mut dyn FnMut(&str) -> bool,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let (status, mut response) = send(url, body, api_key, timeout)?;
+
+    if !(200..300).contains(&status) {
+        let mut response_body = String::new();
+        response.read_to_string(&mut response_body)?;
+
+        return Err(format!("HTTP {}: {}", status, response_body).into());
+    }
+
+    for line in BufReader::new(response).lines() {
+        if !on_line(&line?) {
+            break;
+        }
+    }
+
+    Ok(())
+}
+
+// Send the request and return the status code with a reader over the response body
+fn send(
+    url: &str,
+    body: &str,
+    api_key: Option<&str>,
+    timeout: Duration,
+) -> Result<(u16, Box<dyn Read>), Box<dyn std::error::Error>> {
+    let url = Url::parse(url)?;
+
+    let mut stream = connect(&url, timeout)?;
+    stream.set_read_timeout(Some(timeout))?;
+    stream.set_write_timeout(Some(timeout))?;
+
+    // Build and send request
+    let mut request = format!(
+        "POST {} HTTP/1.1\r\nHost: {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n",
+        url.path,
+        url.authority(),
+        body.len()
+    );
+
+    if let Some(api_key) = api_key {
+        request.push_str(&format!("Authorization: Bearer {}\r\n", api_key));
+    }
+
+    request.push_str("\r\n");
+    request.push_str(body);
+
+    stream.write_all(request.as_bytes())?;
+    stream.flush()?;
+
+    // Read status line and headers
+    let mut reader = BufReader::new(stream);
+
+    let mut status_line = String::new();
+    reader.read_line(&mut status_line)?;
+
+    let status: u16 = status_line
+        .split_whitespace()
+        .nth(1)
+        .and_then(|code| code.parse().ok())
+        .ok_or_else(|| format!("Malformed HTTP status line: {}", status_line.trim()))?;
+
+    let mut chunked = false;
+    let mut content_length = None;
+
+    loop {
+        let mut header = String::new();
+        if reader.read_line(&mut header)? == 0 {
+            break;
+        }
+
+        let header = header.trim();
+        if header.is_empty() {
+            break;
+        }
+
+        if let Some((name, value)) = header.split_once(':') {
+            let name = name.trim().to_ascii_lowercase();
+            let value = value.trim();
+
+            if name == "transfer-encoding" && value.eq_ignore_ascii_case("chunked") {
+                chunked = true;
+            } else if name == "content-length" {
+                content_length = value.parse::<u64>().ok();
+            }
+        }
+    }
+
+    // Wrap the body so callers don't need to care how it was framed
+    let response: Box<dyn Read> = if chunked {
+        Box::new(ChunkedReader::new(reader))
+    } else if let Some(length) = content_length {
+        Box::new(LengthReader::new(reader, length))
+    } else {
+        Box::new(reader)
+    };
+
+    Ok((status, response))
+}
+
+// Connect to the first address the host resolves to that answers within the timeout
+fn connect(url: &Url, timeout: Duration) -> Result<TcpStream, Box<dyn std::error::Error>> {
+    let mut last_error = None;
+
+    for address in (url.host.as_str(), url.port).to_socket_addrs()? {
+        match TcpStream::connect_timeout(&address, timeout) {
+            Ok(stream) => return Ok(stream),
+            Err(e) => last_error = Some(e),
+        }
+    }
+
+    Err(match last_error {
+        Some(e) => format!("Failed to connect to {}: {}", url.authority(), e).into(),
+        None => format!("No addresses found for {}", url.host).into(),
+    })
+}
+
+// Struct for reading a body of known length, a connection that closes early is an error rather than a short body
+struct LengthReader<R: Read> {
+    inner: R,
+    remaining: u64,
+}
+
+// Functions for Length Reader
+impl<R: Read> LengthReader<R> {
+    // Create a new Length Reader
+    fn new(inner: R, length: u64) -> Self {
+        Self {
+            inner,
+            remaining: length,
+        }
+    }
+}
+
+impl<R: Read> Read for LengthReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        if self.remaining == 0 || buf.is_empty() {
+            return Ok(0);
+        }
+
+        let max = buf
+            .len()
+            .min(self.remaining.min(usize::MAX as u64) as usize);
+        let read = self.inner.read(&mut buf[..max])?;
+        if read == 0 {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::UnexpectedEof,
+                format!("Response ended {} bytes short", self.remaining),
+            ));
+        }
+
+        self.remaining -= read as u64;
+
+        Ok(read)
+    }
+}
+
+// Struct for reading a chunked transfer-encoded body as a plain stream
+struct ChunkedReader<R: BufRead> {
+    inner: R,
+    remaining: usize,
+    finished: bool,
+}
+
+// Functions for Chunked Reader
+impl<R: BufRead> ChunkedReader<R> {
+    // Create a new Chunked Reader
+    fn new(inner: R) -> Self {
+        Self {
+            inner,
+            remaining: 0,
+            finished: false,
+        }
+    }
+
+    // Read the next chunk size line, returns 0 for the final chunk
+    fn next_chunk_size(&mut self) -> std::io::Result<usize> {
+        let mut size_line = String::new();
+        self.inner.read_line(&mut size_line)?;
+
+        // Chunk extensions (after ';') are ignored
+        let size_hex = size_line.trim().split(';').next().unwrap_or("");
+
+        usize::from_str_radix(size_hex, 16).map_err(|_| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                format!("Malformed chunk size: {}", size_line.trim()),
+            )
+        })
+    }
+}
+
+impl<R: BufRead> Read for ChunkedReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        if self.finished || buf.is_empty() {
+            return Ok(0);
+        }
+
+        if self.remaining == 0 {
+            self.remaining = self.next_chunk_size()?;
+
+            if self.remaining == 0 {
+                self.finished = true;
+                return Ok(0);
+            }
+        }
+
+        let max = buf.len().min(self.remaining);
+        let read = self.inner.read(&mut buf[..max])?;
+        if read == 0 {
+            return Err(std::io::ErrorKind::UnexpectedEof.into());
+        }
+
+        self.remaining -= read;
+
+        // Skip the CRLF after each chunk
+        if self.remaining == 0 {
+            let mut crlf = String::new();
+            self.inner.read_line(&mut crlf)?;
+        }
+
+        Ok(read)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_host_port_and_path() {
+        let url = Url::parse("http://localhost:11434/v1/chat/completions").unwrap();
+
+        assert_eq!(url.host, "localhost");
+        assert_eq!(url.port, 11434);
+        assert_eq!(url.path, "/v1/chat/completions");
+        assert_eq!(url.authority(), "localhost:11434");
+    }
+
+    #[test]
+    fn defaults_to_port_80_and_root_path() {
+        let url = Url::parse("http://example.com").unwrap();
+
+        assert_eq!(url.port, 80);
+        assert_eq!(url.path, "/");
+    }
+
+    #[test]
+    fn parses_bracketed_ipv6_hosts() {
+        let url = Url::parse("http://[::1]:8080/api").unwrap();
+        assert_eq!(url.host, "::1");
+        assert_eq!(url.port, 8080);
+        assert_eq!(url.path, "/api");
+        assert_eq!(url.authority(), "[::1]:8080");
+
+        let url = Url::parse("http://[fe80::1]/").unwrap();
+        assert_eq!(url.host, "fe80::1");
+        assert_eq!(url.port, 80);
+
+        assert!(Url::parse("http://[::1/api").is_err());
+        assert!(Url::parse("http://[::1]8080/").is_err());
+    }
+
+    #[test]
+    fn rejects_https_with_a_config_hint() {
+        let error = validate_url("https://api.openai.com/v1/chat/completions")
+            .unwrap_err()
+            .to_string();
+
+        assert!(error.starts_with("HTTPS isn't supported"), "{}", error);
+        assert!(error.contains("ai_url"), "{}", error);
+    }
+
+    #[test]
+    fn rejects_other_schemes_and_missing_hosts() {
+        assert!(validate_url("ftp://localhost/").is_err());
+        assert!(validate_url("http:///path").is_err());
+        assert!(validate_url("http://localhost:notaport/").is_err());
+    }
+
+    #[test]
+    fn reads_chunked_bodies() {
+        let body = "5\r\nhello\r\n7;ext=1\r\n, world\r\n0\r\n\r\n";
+        let mut reader = ChunkedReader::new(BufReader::new(body.as_bytes()));
+
+        let mut text = String::new();
+        reader.read_to_string(&mut text).unwrap();
+
+        assert_eq!(text, "hello, world");
+    }
+}
@@ -0,0 +1,212 @@
+use serde::{Deserialize, Serialize};
+
+use super::dialogue::DialogueLine;
+
+// Struct for what an NPC remembers of the player, a rolling transcript plus a summary of older talk
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DialogueMemory {
+    pub summary: String,
+    pub transcript: Vec<DialogueLine>,
+}
+
+// Functions for Dialogue Memory
+impl DialogueMemory {
+    // Number of characters held in memory
+    pub fn char_count(&self) -> usize {
+        self.summary.chars().count()
+            + self
+                .transcript
+                .iter()
+                .map(|line| line.text.chars().count())
+                .sum::<usize>()
+    }
+
+    // Check whether the memory has grown past its budget and there are lines that can be folded away
+    pub fn over_budget(&self, budget: &MemoryBudget) -> bool {
+        self.char_count() > budget.max_chars && self.oldest_to_take(budget) > 0
+    }
+
+    // Remove and return the oldest lines, keeping the most recent ones in the transcript
+    pub fn take_oldest(&mut self, budget: &MemoryBudget) -> Vec<DialogueLine> {
+        let split = self.oldest_to_take(budget);
+
+        self.transcript.drain(..split).collect()
+    }
+
+    // Number of old lines to fold away, the recent lines kept are capped at their share of the budget
+    // so a few very long lines can't hold memory over budget, though the latest line is always kept
+    fn oldest_to_take(&self, budget: &MemoryBudget) -> usize {
+        let max_recent_chars = budget.max_chars - budget.max_chars / 2;
+        let mut split = self.transcript.len().saturating_sub(budget.keep_recent);
+        let mut recent_chars: usize = self.transcript[split..]
+            .iter()
+            .map(|line| line.text.chars().count())
+            .sum();
+
+        while recent_chars > max_recent_chars && split + 1 < self.transcript.len() {
+            recent_chars -= self.transcript[split].text.chars().count();
+            split += 1;
+        }
+
+        split
+    }
+
+    // Replace the summary, trimming from the front if it alone would exceed its share of the budget
+    pub fn set_summary(&mut self, summary: String, budget: &MemoryBudget) {
+        let max_summary_chars = budget.max_chars / 2;
+        let count = summary.chars().count();
+
+        self.summary = if count > max_summary_chars {
+            trim_front(&summary, count - max_summary_chars)
+        } else {
+            summary
+        };
+    }
+}
+
+// Drop at least the given number of characters from the front of some text, starting what's left
+// at the next sentence, or failing that the next word, so the summary doesn't open mid-sentence
+fn trim_front(text: &str, skip_chars: usize) -> String {
+    let start = text
+        .char_indices()
+        .nth(skip_chars)
+        .map_or(text.len(), |(index, _)| index);
+    let (dropped, tail) = text.split_at(start);
+
+    let kept = if dropped.ends_with(char::is_whitespace)
+        && dropped.trim_end().ends_with(['.', '!', '?'])
+    {
+        Some(tail)
+    } else {
+        tail.char_indices()
+            .find(|&(index, c)| {
+                matches!(c, '.' | '!' | '?') && tail[index + 1..].starts_with(char::is_whitespace)
+            })
+            .map(|(index, _)| &tail[index + 1..])
+            .filter(|rest| !rest.trim().is_empty())
+    };
+
+    let kept = kept.unwrap_or_else(|| {
+        if dropped.ends_with(char::is_whitespace) {
+            tail
+        } else {
+            // Skip the rest of the word the cut landed in, unless the whole tail is one word
+            tail.find(char::is_whitespace)
+                .map_or(tail, |index| &tail[index..])
+        }
+    });
+
+    kept.trim_start().to_string()
+}
+
+// Struct for the size limits applied to each NPC's memory
+#[derive(Clone)]
+pub struct MemoryBudget {
+    pub max_chars: usize,
+    pub keep_recent: usize,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ai::dialogue::Speaker;
+
+    fn budget() -> MemoryBudget {
+        MemoryBudget {
+            max_chars: 100,
+            keep_recent: 2,
+        }
+    }
+
+    fn memory(lines: &[&str]) -> DialogueMemory {
+        DialogueMemory {
+            summary: String::new(),
+            transcript: lines
+                .iter()
+                .map(|text| DialogueLine {
+                    speaker: Speaker::Player,
+                    text: text.to_string(),
+                })
+                .collect(),
+        }
+    }
+
+    fn texts(lines: &[DialogueLine]) -> Vec<&str> {
+        lines.iter().map(|line| line.text.as_str()).collect()
+    }
+
+    #[test]
+    fn over_budget_once_past_the_limit_with_lines_to_spare() {
+        let line = "x".repeat(30);
+
+        assert!(!memory(&[&line, &line, &line]).over_budget(&budget()));
+        assert!(memory(&[&line, &line, &line, &line]).over_budget(&budget()));
+
+        // Only the recent lines, and they fit their share, so there's nothing to fold away
+        let short = "x".repeat(25);
+        let mut recent = memory(&[&short, &short]);
+        recent.summary = "y".repeat(55);
+        assert!(!recent.over_budget(&budget()));
+    }
+
+    #[test]
+    fn takes_the_oldest_lines_and_keeps_the_recent_ones() {
+        let mut memory = memory(&["one", "two", "three", "four"]);
+        let oldest = memory.take_oldest(&budget());
+
+        assert_eq!(texts(&oldest), ["one", "two"]);
+        assert_eq!(texts(&memory.transcript), ["three", "four"]);
+        assert!(memory.take_oldest(&budget()).is_empty());
+    }
+
+    #[test]
+    fn caps_the_recent_lines_at_their_share_of_the_budget() {
+        let long = "x".repeat(45);
+        let mut memory = memory(&["an older line", &long, &long]);
+        assert!(memory.over_budget(&budget()));
+
+        // Two long lines are over half the budget, so only the latest is kept
+        let oldest = memory.take_oldest(&budget());
+        assert_eq!(oldest.len(), 2);
+        assert_eq!(memory.transcript.len(), 1);
+
+        // The latest line stays however long it is
+        let mut memory = self::memory(&[&"x".repeat(200)]);
+        assert!(!memory.over_budget(&budget()));
+        assert!(memory.take_oldest(&budget()).is_empty());
+    }
+
+    #[test]
+    fn keeps_short_summaries_whole() {
+        let mut memory = DialogueMemory::default();
+        memory.set_summary("The player asked about the well.".to_string(), &budget());
+
+        assert_eq!(memory.summary, "The player asked about the well.");
+    }
+
+    #[test]
+    fn trims_long_summaries_at_a_sentence() {
+        let mut memory = DialogueMemory::default();
+        let summary = "The player asked about the old well behind the mill. \
+                       They were told it dried up years ago. They promised to return."
+            .to_string();
+        memory.set_summary(summary, &budget());
+
+        assert_eq!(memory.summary, "They promised to return.");
+    }
+
+    #[test]
+    fn trims_long_summaries_at_a_word_without_a_sentence_break() {
+        let mut memory = DialogueMemory::default();
+        let summary = "the player asked about the old well behind the mill and was told \
+                       it dried up years ago and promised to return"
+            .to_string();
+        memory.set_summary(summary, &budget());
+
+        assert_eq!(
+            memory.summary,
+            "told it dried up years ago and promised to return"
+        );
+        assert!(memory.summary.chars().count() <= 50);
+    }
+}
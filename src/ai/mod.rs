@@ -0,0 +1,5 @@
+pub mod dialogue;
+pub mod http;
+pub mod memory;
+pub mod narrator;
+pub mod provider;
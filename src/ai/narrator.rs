@@ -0,0 +1,483 @@
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, RwLock};
+use std::thread;
+use std::time::Duration;
+
+use crate::world::manager::{BuildingType, ContainerType};
+use crate::world::time::Phase;
+use crate::world::weather::WeatherType;
+
+// Trait for anything that can describe a scene
+pub trait NarratorBackend: Send + Sync {
+    fn describe(&self, scene: &Scene) -> Result<String, Box<dyn std::error::Error>>;
+}
+
+// Create a Narrator Backend based on the config
+pub fn from_config(config: &crate::core::config::Config) -> Arc<dyn NarratorBackend> {
+    match config.narrator_provider {
+        crate::core::config::ProviderType::Http => Arc::new(super::provider::HttpProvider::new(
+            config.ai_url.clone(),
+            config.ai_model.clone(),
+            config.ai_api_key.clone(),
+            Duration::from_secs(config.ai_timeout_secs),
+        )),
+        crate::core::config::ProviderType::Template | crate::core::config::ProviderType::Mock => {
+            Arc::new(TemplateNarrator {})
+        }
+    }
+}
+
+// Struct for Narrator Manager
+pub struct NarratorManager {
+    backend: Arc<dyn NarratorBackend>,
+    pub scene_cache_arc_rwlock: Arc<RwLock<HashMap<SceneKey, String>>>,
+    requested: HashSet<SceneKey>,
+    pub current_scene: Option<SceneKey>,
+}
+
+// Functions for Narrator Manager
+impl NarratorManager {
+    // Create a new Narrator Manager using the backend from the config
+    pub fn new(config: &crate::core::config::Config) -> Self {
+        Self {
+            backend: from_config(config),
+            scene_cache_arc_rwlock: Arc::new(RwLock::new(HashMap::new())),
+            requested: HashSet::new(),
+            current_scene: None,
+        }
+    }
+
+    // Clear all cached descriptions
+    pub fn clear(&mut self) {
+        self.scene_cache_arc_rwlock = Arc::new(RwLock::new(HashMap::new()));
+        self.requested.clear();
+        self.current_scene = None;
+    }
+
+    // Track the player's current scene, generating a description the first time it's seen
+    pub fn update(&mut self, scene: Option<Scene>) {
+        let Some(scene) = scene else {
+            self.current_scene = None;
+            return;
+        };
+
+        self.current_scene = Some(scene.key.clone());
+
+        if !self.requested.insert(scene.key.clone()) {
+            return;
+        }
+
+        let backend = Arc::clone(&self.backend);
+        let scene_cache_arc_clone = Arc::clone(&self.scene_cache_arc_rwlock);
+
+        thread::spawn(move || {
+            let description = match backend.describe(&scene) {
+                Ok(description) => description,
+                Err(e) => {
+                    log::error!("Failed to narrate scene, using template: {}", e);
+
+                    TemplateNarrator {}.describe(&scene).unwrap_or_default()
+                }
+            };
+
+            if let Ok(mut scene_cache) = scene_cache_arc_clone.write() {
+                scene_cache.insert(scene.key, description);
+            }
+        });
+    }
+
+    // Get the description of the current scene, if it's ready
+    pub fn description(&self) -> Option<String> {
+        let key = self.current_scene.as_ref()?;
+
+        self.scene_cache_arc_rwlock
+            .read()
+            .ok()
+            .and_then(|scene_cache| scene_cache.get(key).cloned())
+    }
+}
+
+// Struct for the key scene descriptions are cached by
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SceneKey {
+    location: Location,
+    phase: Phase,
+    weather: WeatherType,
+}
+
+// Enum for where the player is
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum Location {
+    Town(u32),
+    Building(u32),
+    Room(u32),
+}
+
+// Struct for everything the narrator needs to describe a scene
+pub struct Scene {
+    key: SceneKey,
+    town_name: String,
+    building: Option<(String, BuildingType)>,
+    npc_names: Vec<String>,
+    containers: Vec<ContainerType>,
+}
+
+// Functions for Scene
+impl Scene {
+    // Build the scene the player is currently in, if they are somewhere worth describing
+    pub fn current(managers: &crate::ui::display::Managers) -> Option<Self> {
+        match managers.state_manager.current_state {
+            crate::core::states::StateType::Game
+            | crate::core::states::StateType::Explore
+            | crate::core::states::StateType::Building
+            | crate::core::states::StateType::Room
+            | crate::core::states::StateType::Tavern
+            | crate::core::states::StateType::Temple => {}
+            _ => return None,
+        }
+
+        let phase = managers
+            .time_manager
+            .time_arc_rwlock
+            .as_ref()?
+            .read()
+            .ok()?
+            .phase
+            .clone();
+
+        let weather = managers
+            .weather_manager
+            .weather_arc_rwlock
+            .as_ref()?
+            .read()
+            .ok()?
+            .weather_type
+            .clone();
+
+        let town = managers.world_manager.current_town()?;
+        let building = managers.world_manager.current_building();
+        let room = managers.world_manager.current_room();
+
+        let location = match (building, room) {
+            (_, Some(room)) => Location::Room(room.id()),
+            (Some(building), None) => Location::Building(building.id()),
+            (None, None) => Location::Town(town.id()),
+        };
+
+        Some(Self {
+            key: SceneKey {
+                location,
+                phase,
+                weather,
+            },
+            town_name: town.name.clone(),
+            building: building.map(|building| {
+                (
+                    building.name().to_string(),
+                    building.building_type().clone(),
+                )
+            }),
+            npc_names: room
+                .map(|room| {
+                    room.npcs()
+                        .iter()
+                        .map(|npc| npc.name().to_string())
+                        .collect()
+                })
+                .unwrap_or_default(),
+            containers: room
+                .map(|room| {
+                    room.containers()
+                        .iter()
+                        .map(|container| container.container_type().clone())
+                        .collect()
+                })
+                .unwrap_or_default(),
+        })
+    }
+
+    // Describe the scene plainly, used to prompt a language model
+    fn summary(&self) -> String {
+        let place = match (&self.key.location, &self.building) {
+            (Location::Town(_), _) | (_, None) => {
+                format!("the streets of the town of {}", self.town_name)
+            }
+            (Location::Building(_), Some((name, building_type))) => {
+                format!(
+                    "inside {} (a {:?}) in {}",
+                    name, building_type, self.town_name
+                )
+            }
+            (Location::Room(_), Some((name, building_type))) => format!(
+                "a room inside {} (a {:?}) in {}, with {} present and these containers: {:?}",
+                name,
+                building_type,
+                self.town_name,
+                if self.npc_names.is_empty() {
+                    "nobody".to_string()
+                } else {
+                    self.npc_names.join(", ")
+                },
+                self.containers
+            ),
+        };
+
+        format!(
+            "Location: {}. Time of day: {:?}. Weather: {:?}.",
+            place, self.key.phase, self.key.weather
+        )
+    }
+}
+
+// Struct for Template Narrator, deterministic descriptions that work offline
+pub struct TemplateNarrator {}
+
+impl NarratorBackend for TemplateNarrator {
+    fn describe(&self, scene: &Scene) -> Result<String, Box<dyn std::error::Error>> {
+        let description = match (&scene.key.location, &scene.building) {
+            (Location::Town(_), _) | (_, None) => format!(
+                "{} {}",
+                outdoor_phase(&scene.key.phase, &scene.town_name),
+                outdoor_weather(&scene.key.weather)
+            ),
+            (Location::Building(_), Some((name, building_type))) => format!(
+                "{} {}",
+                building_interior(building_type, name),
+                indoor_weather(&scene.key.weather)
+            ),
+            (Location::Room(_), Some(_)) => {
+                let people = match scene.npc_names.as_slice() {
+                    [] => "The room is empty.".to_string(),
+                    [npc] => format!("{} is here.", npc),
+                    npcs => format!(
+                        "{} and {} are here.",
+                        npcs[..npcs.len() - 1].join(", "),
+                        npcs[npcs.len() - 1]
+                    ),
+                };
+
+                let containers = if scene.containers.is_empty() {
+                    String::new()
+                } else {
+                    let names: Vec<String> = scene
+                        .containers
+                        .iter()
+                        .map(|container| format!("{:?}", container).to_lowercase())
+                        .collect();
+                    format!(" You spot: {}.", names.join(", "))
+                };
+
+                format!(
+                    "{} {}{} {}",
+                    indoor_phase(&scene.key.phase),
+                    people,
+                    containers,
+                    indoor_weather(&scene.key.weather)
+                )
+            }
+        };
+
+        Ok(description)
+    }
+}
+
+impl NarratorBackend for super::provider::HttpProvider {
+    fn describe(&self, scene: &Scene) -> Result<String, Box<dyn std::error::Error>> {
+        self.complete(format!(
+            "You are the narrator of a fantasy role-playing game. \
+             Describe this scene to the player in two or three sentences of second-person prose. \
+             Do not invent people who are not listed. {}",
+            scene.summary()
+        ))
+    }
+}
+
+// Outdoor description based on time of day
+fn outdoor_phase(phase: &Phase, town_name: &str) -> String {
+    match phase {
+        Phase::Dawn => format!("Pale dawn light creeps over the rooftops of {}.", town_name),
+        Phase::Day => format!("The streets of {} bustle under the daylight.", town_name),
+        Phase::Dusk => format!(
+            "Lamps are being lit along the streets of {} as dusk settles.",
+            town_name
+        ),
+        Phase::Night => format!("{} lies quiet under a dark sky.", town_name),
+    }
+}
+
+// Outdoor description based on the weather
+fn outdoor_weather(weather: &WeatherType) -> &'static str {
+    match weather {
+        WeatherType::Sunny => "The sun is warm on your face.",
+        WeatherType::Raining => "Rain runs in rivulets between the cobbles.",
+        WeatherType::Windy => "A strong wind tugs at your cloak.",
+        WeatherType::Stormy => "Thunder rolls overhead and the streets are all but empty.",
+        WeatherType::Snowing => "Snow drifts down, muffling every footstep.",
+    }
+}
+
+// Indoor description based on time of day
+fn indoor_phase(phase: &Phase) -> &'static str {
+    match phase {
+        Phase::Dawn => "Early light filters through the shutters.",
+        Phase::Day => "Daylight spills across the floorboards.",
+        Phase::Dusk => "Long shadows stretch across the room.",
+        Phase::Night => "A single candle gutters in the gloom.",
+    }
+}
+
+// Indoor description based on the weather
+fn indoor_weather(weather: &WeatherType) -> &'static str {
+    match weather {
+        WeatherType::Sunny => "Outside, the day is bright.",
+        WeatherType::Raining => "Rain patters against the windows.",
+        WeatherType::Windy => "The wind rattles the door in its frame.",
+        WeatherType::Stormy => "The walls shudder with each crack of thunder.",
+        WeatherType::Snowing => "Cold seeps in from the snow outside.",
+    }
+}
+
+// Description of a building's interior based on its type
+fn building_interior(building_type: &BuildingType, name: &str) -> String {
+    match building_type {
+        BuildingType::Residence => format!("{} is a modest home, lived in and cluttered.", name),
+        BuildingType::Shop => format!("Shelves crowded with wares line the walls of {}.", name),
+        BuildingType::Tavern => format!("The smell of ale and woodsmoke fills {}.", name),
+        BuildingType::Temple => format!("Candles flicker in the hushed halls of {}.", name),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::time::Instant;
+
+    fn scene(location: Location, npc_names: &[&str]) -> Scene {
+        Scene {
+            key: SceneKey {
+                location,
+                phase: Phase::Dusk,
+                weather: WeatherType::Raining,
+            },
+            town_name: "Ash Burhwich".into(),
+            building: Some(("The Golden Hind".into(), BuildingType::Tavern)),
+            npc_names: npc_names.iter().map(|name| name.to_string()).collect(),
+            containers: vec![ContainerType::Barrel, ContainerType::Chest],
+        }
+    }
+
+    fn describe(scene: &Scene) -> String {
+        TemplateNarrator {}.describe(scene).unwrap()
+    }
+
+    #[test]
+    fn describes_the_town_streets() {
+        let mut scene = scene(Location::Town(1), &["Ada"]);
+        scene.building = None;
+
+        assert_eq!(
+            describe(&scene),
+            "Lamps are being lit along the streets of Ash Burhwich as dusk settles. \
+             Rain runs in rivulets between the cobbles."
+        );
+    }
+
+    #[test]
+    fn describes_a_building() {
+        assert_eq!(
+            describe(&scene(Location::Building(2), &[])),
+            "The smell of ale and woodsmoke fills The Golden Hind. \
+             Rain patters against the windows."
+        );
+    }
+
+    #[test]
+    fn describes_a_room_with_its_people_and_containers() {
+        assert_eq!(
+            describe(&scene(Location::Room(3), &["Ada"])),
+            "Long shadows stretch across the room. Ada is here. \
+             You spot: barrel, chest. Rain patters against the windows."
+        );
+
+        // A room outside any building falls back to the streets
+        let mut outside = scene(Location::Room(3), &["Ada"]);
+        outside.building = None;
+        assert!(describe(&outside).starts_with("Lamps are being lit"));
+    }
+
+    #[test]
+    fn joins_the_people_in_a_room() {
+        let people = |names: &[&str]| {
+            let mut scene = scene(Location::Room(3), names);
+            scene.containers.clear();
+            describe(&scene)
+        };
+
+        assert!(people(&[]).contains(" The room is empty. "));
+        assert!(people(&["Ada"]).contains(" Ada is here. "));
+        assert!(people(&["Ada", "Bram"]).contains(" Ada and Bram are here. "));
+        assert!(people(&["Ada", "Bram", "Cole"]).contains(" Ada, Bram and Cole are here. "));
+    }
+
+    // Struct for a backend that counts how many descriptions it was asked for
+    struct CountingNarrator {
+        calls: Arc<AtomicUsize>,
+    }
+
+    impl NarratorBackend for CountingNarrator {
+        fn describe(&self, scene: &Scene) -> Result<String, Box<dyn std::error::Error>> {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+
+            Ok(format!("Scene in {}", scene.town_name))
+        }
+    }
+
+    fn wait_for_description(manager: &NarratorManager) -> String {
+        let started = Instant::now();
+
+        loop {
+            if let Some(description) = manager.description() {
+                return description;
+            }
+
+            assert!(started.elapsed() < Duration::from_secs(5), "no description");
+            thread::sleep(Duration::from_millis(10));
+        }
+    }
+
+    #[test]
+    fn generates_each_scene_only_once() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let mut manager = NarratorManager {
+            backend: Arc::new(CountingNarrator {
+                calls: Arc::clone(&calls),
+            }),
+            scene_cache_arc_rwlock: Arc::new(RwLock::new(HashMap::new())),
+            requested: HashSet::new(),
+            current_scene: None,
+        };
+
+        manager.update(Some(scene(Location::Room(3), &[])));
+        manager.update(Some(scene(Location::Room(3), &["Ada"])));
+        assert_eq!(wait_for_description(&manager), "Scene in Ash Burhwich");
+
+        manager.update(Some(scene(Location::Room(3), &[])));
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+
+        // Leaving and coming back reuses the cached description
+        manager.update(None);
+        assert!(manager.description().is_none());
+        manager.update(Some(scene(Location::Room(3), &[])));
+        assert_eq!(
+            manager.description().as_deref(),
+            Some("Scene in Ash Burhwich")
+        );
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+
+        // A different scene is generated afresh
+        manager.update(Some(scene(Location::Building(2), &[])));
+        wait_for_description(&manager);
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+}
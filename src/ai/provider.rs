@@ -0,0 +1,742 @@
+use serde_json::json;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+
+use super::dialogue::{DialogueContext, DialogueLine, Speaker};
+use super::memory::DialogueMemory;
+use crate::world::manager::BuildingType;
+use crate::world::time::Phase;
+use crate::world::weather::WeatherType;
+
+// Trait for anything that can generate NPC dialogue, shared with the generation thread
+pub trait DialogueProvider: Send + Sync {
+    // Generate the NPC's next line, the last line of the transcript is what the player just said
+    fn reply(
+        &self,
+        context: &DialogueContext,
+        memory: &DialogueMemory,
+    ) -> Result<String, Box<dyn std::error::Error>>;
+
+    // Generate the NPC's next line, passing each piece to on_token as it arrives
+    // Stops early if on_token returns false, returns everything generated
+    fn stream_reply(
+        &self,
+        context: &DialogueContext,
+        memory: &DialogueMemory,
+        on_token: &mut dyn FnMut(&str) -> bool,
+    ) -> Result<String, Box<dyn std::error::Error>> {
+        let reply = self.reply(context, memory)?;
+        on_token(&reply);
+
+        Ok(reply)
+    }
+
+    // Fold older lines of dialogue into the NPC's summary of past conversations
+    fn summarize(
+        &self,
+        context: &DialogueContext,
+        summary: &str,
+        lines: &[DialogueLine],
+    ) -> Result<String, Box<dyn std::error::Error>>;
+}
+
+// Create a Dialogue Provider based on the config
+pub fn from_config(config: &crate::core::config::Config) -> Arc<dyn DialogueProvider> {
+    match config.dialogue_provider {
+        crate::core::config::ProviderType::Template => Arc::new(TemplateProvider::new()),
+        crate::core::config::ProviderType::Http => Arc::new(HttpProvider::new(
+            config.ai_url.clone(),
+            config.ai_model.clone(),
+            config.ai_api_key.clone(),
+            Duration::from_secs(config.ai_timeout_secs),
+        )),
+        crate::core::config::ProviderType::Mock => Arc::new(MockProvider::new(vec![
+            "Mock reply.".into(),
+            "Another mock reply.".into(),
+        ])),
+    }
+}
+
+// Struct for Template Provider, deterministic replies that work offline
+pub struct TemplateProvider {}
+
+// Functions for Template Provider
+impl TemplateProvider {
+    // Create a new Template Provider
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl DialogueProvider for TemplateProvider {
+    fn reply(
+        &self,
+        context: &DialogueContext,
+        memory: &DialogueMemory,
+    ) -> Result<String, Box<dyn std::error::Error>> {
+        let transcript = &memory.transcript;
+
+        let input = transcript
+            .iter()
+            .rev()
+            .find(|line| matches!(line.speaker, Speaker::Player))
+            .map(|line| line.text.to_lowercase())
+            .unwrap_or_default();
+
+        // First thing said gets a greeting, returning visitors are recognised
+        if context.new_conversation && (transcript.len() > 1 || !memory.summary.is_empty()) {
+            return Ok(format!("Back again? {}", greeting(&context.phase)));
+        }
+        if context.new_conversation {
+            return Ok(format!(
+                "{} {}",
+                greeting(&context.phase),
+                building_remark(&context.building_type, &context.building_name)
+            ));
+        }
+
+        // Respond to a few keywords
+        if input.contains("bye") || input.contains("farewell") {
+            return Ok("Safe travels, friend.".into());
+        }
+        if input.contains("name") {
+            return Ok(format!("Name's {}. And you'd be?", context.npc_name));
+        }
+        if input.contains("town") || input.contains("place") {
+            return Ok(format!(
+                "{}? Quiet enough, most days. Folk keep to themselves.",
+                context.town_name
+            ));
+        }
+        if input.contains("weather") || input.contains("rain") || input.contains("sun") {
+            return Ok(weather_remark(&context.weather).into());
+        }
+
+        // Otherwise pick a generic line, deterministic for the same input
+        let generic = [
+            "Is that so? Can't say I've heard much about it.",
+            "Hmm. You're not from around here, are you?",
+            "I'd not go spreading that about if I were you.",
+            weather_remark(&context.weather),
+            "Ask around the tavern, someone there will know more.",
+        ];
+        let seed = input.bytes().map(|b| b as usize).sum::<usize>() + transcript.len();
+
+        Ok(generic[seed % generic.len()].into())
+    }
+
+    fn stream_reply(
+        &self,
+        context: &DialogueContext,
+        memory: &DialogueMemory,
+        on_token: &mut dyn FnMut(&str) -> bool,
+    ) -> Result<String, Box<dyn std::error::Error>> {
+        let reply = self.reply(context, memory)?;
+        let mut streamed = String::new();
+
+        // Hand out a word at a time so replies read like the HTTP provider's
+        for word in reply.split_inclusive(' ') {
+            if !on_token(word) {
+                break;
+            }
+            streamed.push_str(word);
+
+            thread::sleep(Duration::from_millis(40));
+        }
+
+        Ok(streamed)
+    }
+
+    fn summarize(
+        &self,
+        _context: &DialogueContext,
+        summary: &str,
+        lines: &[DialogueLine],
+    ) -> Result<String, Box<dyn std::error::Error>> {
+        // Remember the gist of what the player said, a few words per line
+        let topics: Vec<String> = lines
+            .iter()
+            .filter(|line| matches!(line.speaker, Speaker::Player))
+            .map(|line| {
+                line.text
+                    .split_whitespace()
+                    .take(6)
+                    .collect::<Vec<&str>>()
+                    .join(" ")
+            })
+            .collect();
+
+        if topics.is_empty() {
+            return Ok(summary.to_string());
+        }
+
+        let mut new_summary = summary.to_string();
+        if !new_summary.is_empty() {
+            new_summary.push(' ');
+        }
+        new_summary.push_str(&format!("The traveller spoke of: {}.", topics.join("; ")));
+
+        Ok(new_summary)
+    }
+}
+
+// Greeting based on time of day
+fn greeting(phase: &Phase) -> &'static str {
+    match phase {
+        Phase::Dawn => "You're up early.",
+        Phase::Day => "Good day to you.",
+        Phase::Dusk => "Evening's drawing in.",
+        Phase::Night => "Bit late to be wandering about, isn't it?",
+    }
+}
+
+// Remark based on the building the NPC is in
+fn building_remark(building_type: &BuildingType, building_name: &str) -> String {
+    match building_type {
+        BuildingType::Residence => "This is my home, stranger. Make it quick.".into(),
+        BuildingType::Shop => format!("Welcome to {}. Looking to buy something?", building_name),
+        BuildingType::Tavern => format!("Welcome to {}. Pull up a stool.", building_name),
+        BuildingType::Temple => "The gods watch over all who enter here.".into(),
+    }
+}
+
+// Remark based on the current weather
+fn weather_remark(weather: &WeatherType) -> &'static str {
+    match weather {
+        WeatherType::Sunny => "Fine weather we're having.",
+        WeatherType::Raining => "Mind you don't drip all over the floor.",
+        WeatherType::Windy => "Wind's fierce today, hold on to your hat.",
+        WeatherType::Stormy => "Best stay inside till this storm passes.",
+        WeatherType::Snowing => "Snow's coming down thick out there.",
+    }
+}
+
+// Struct for HTTP Provider, talks to an OpenAI/Ollama-compatible chat endpoint
+pub struct HttpProvider {
+    url: String,
+    model: String,
+    api_key: Option<String>,
+    timeout: Duration,
+}
+
+// Functions for HTTP Provider
+impl HttpProvider {
+    // Create a new HTTP Provider
+    pub fn new(url: String, model: String, api_key: Option<String>, timeout: Duration) -> Self {
+        Self {
+            url,
+            model,
+            api_key,
+            timeout,
+        }
+    }
+}
+
+impl DialogueProvider for HttpProvider {
+    fn reply(
+        &self,
+        context: &DialogueContext,
+        memory: &DialogueMemory,
+    ) -> Result<String, Box<dyn std::error::Error>> {
+        self.chat(self.reply_messages(context, memory))
+    }
+
+    fn stream_reply(
+        &self,
+        context: &DialogueContext,
+        memory: &DialogueMemory,
+        on_token: &mut dyn FnMut(&str) -> bool,
+    ) -> Result<String, Box<dyn std::error::Error>> {
+        let body = json!({
+            "model": self.model,
+            "messages": self.reply_messages(context, memory),
+            "stream": true,
+        });
+
+        let mut reply = String::new();
+
+        super::http::post_json_lines(
+            &self.url,
+            &body.to_string(),
+            self.api_key.as_deref(),
+            self.timeout,
+            &mut |line| {
+                // OpenAI streams server-sent events, Ollama streams one JSON object per line
+                let data = line.strip_prefix("data:").unwrap_or(line).trim();
+                if data.is_empty() {
+                    return true;
+                }
+                if data == "[DONE]" {
+                    return false;
+                }
+
+                let Ok(value) = serde_json::from_str::<serde_json::Value>(data) else {
+                    log::error!("Skipping malformed stream line: {}", data);
+                    return true;
+                };
+
+                let token = value
+                    .pointer("/choices/0/delta/content")
+                    .or_else(|| value.pointer("/message/content"))
+                    .or_else(|| value.pointer("/response"))
+                    .and_then(|content| content.as_str())
+                    .unwrap_or("");
+
+                if !token.is_empty() {
+                    if !on_token(token) {
+                        return false;
+                    }
+                    reply.push_str(token);
+                }
+
+                // Ollama marks its final line with done
+                !value
+                    .get("done")
+                    .and_then(|done| done.as_bool())
+                    .unwrap_or(false)
+            },
+        )?;
+
+        Ok(reply.trim().to_string())
+    }
+
+    fn summarize(
+        &self,
+        context: &DialogueContext,
+        summary: &str,
+        lines: &[DialogueLine],
+    ) -> Result<String, Box<dyn std::error::Error>> {
+        let dialogue: Vec<String> = lines
+            .iter()
+            .map(|line| match line.speaker {
+                Speaker::Player => format!("Traveller: {}", line.text),
+                Speaker::Npc => format!("{}: {}", context.npc_name, line.text),
+            })
+            .collect();
+
+        let prompt = format!(
+            "You are {name}. Here is what you already remember about a traveller: \"{summary}\"\n\
+             Here is more of your conversation with them:\n{dialogue}\n\
+             Write an updated memory of the traveller from {name}'s point of view in at most three sentences.",
+            name = context.npc_name,
+            summary = summary,
+            dialogue = dialogue.join("\n"),
+        );
+
+        self.complete(prompt)
+    }
+}
+
+// Functions for HTTP Provider
+impl HttpProvider {
+    // Build the chat messages for the NPC's next reply
+    fn reply_messages(
+        &self,
+        context: &DialogueContext,
+        memory: &DialogueMemory,
+    ) -> Vec<serde_json::Value> {
+        let mut system_prompt = context.system_prompt();
+        if !memory.summary.is_empty() {
+            system_prompt.push_str(&format!(
+                " What you remember of earlier conversations with this traveller: {}",
+                memory.summary
+            ));
+        }
+
+        let mut messages = vec![json!({
+            "role": "system",
+            "content": system_prompt,
+        })];
+
+        messages.extend(memory.transcript.iter().map(|line| {
+            let role = match line.speaker {
+                Speaker::Player => "user",
+                Speaker::Npc => "assistant",
+            };
+            json!({ "role": role, "content": line.text })
+        }));
+
+        messages
+    }
+
+    // Send a single prompt to the endpoint and return the reply content
+    pub fn complete(&self, prompt: String) -> Result<String, Box<dyn std::error::Error>> {
+        self.chat(vec![json!({ "role": "user", "content": prompt })])
+    }
+
+    // Send chat messages to the endpoint and return the reply content
+    fn chat(&self, messages: Vec<serde_json::Value>) -> Result<String, Box<dyn std::error::Error>> {
+        let body = json!({
+            "model": self.model,
+            "messages": messages,
+            "stream": false,
+        });
+
+        let response = super::http::post_json(
+            &self.url,
+            &body.to_string(),
+            self.api_key.as_deref(),
+            self.timeout,
+        )?;
+
+        let value: serde_json::Value = serde_json::from_str(&response)?;
+
+        // OpenAI format first, then Ollama's native chat and generate formats
+        let content = value
+            .pointer("/choices/0/message/content")
+            .or_else(|| value.pointer("/message/content"))
+            .or_else(|| value.pointer("/response"))
+            .and_then(|content| content.as_str())
+            .ok_or("Response did not contain any message content.")?;
+
+        Ok(content.trim().to_string())
+    }
+}
+
+// Struct for Mock Provider, cycles through canned replies
+pub struct MockProvider {
+    responses: Vec<String>,
+    calls: AtomicUsize,
+}
+
+// Functions for Mock Provider
+impl MockProvider {
+    // Create a new Mock Provider
+    pub fn new(responses: Vec<String>) -> Self {
+        Self {
+            responses,
+            calls: AtomicUsize::new(0),
+        }
+    }
+}
+
+impl DialogueProvider for MockProvider {
+    fn reply(
+        &self,
+        _context: &DialogueContext,
+        _memory: &DialogueMemory,
+    ) -> Result<String, Box<dyn std::error::Error>> {
+        let call = self.calls.fetch_add(1, Ordering::Relaxed);
+
+        self.responses
+            .get(call % self.responses.len().max(1))
+            .cloned()
+            .ok_or_else(|| "Mock provider has no responses.".into())
+    }
+
+    fn summarize(
+        &self,
+        _context: &DialogueContext,
+        summary: &str,
+        lines: &[DialogueLine],
+    ) -> Result<String, Box<dyn std::error::Error>> {
+        Ok(format!("{} [{} lines summarized]", summary, lines.len())
+            .trim()
+            .to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+
+    fn context() -> DialogueContext {
+        DialogueContext {
+            npc_name: "Ada".into(),
+            npc_race: Default::default(),
+            npc_sex: Default::default(),
+            building_name: "The Golden Hind".into(),
+            building_type: BuildingType::Tavern,
+            town_name: "Ash Burhwich".into(),
+            phase: Phase::Day,
+            weather: WeatherType::Sunny,
+            new_conversation: false,
+        }
+    }
+
+    fn memory() -> DialogueMemory {
+        DialogueMemory {
+            summary: String::new(),
+            transcript: vec![DialogueLine {
+                speaker: Speaker::Player,
+                text: "Hello there".into(),
+            }],
+        }
+    }
+
+    // Stand in for a chat endpoint on localhost, answering one request by writing out each part in turn
+    // with a pause between them, so the client sees the response arrive in pieces
+    // Returns the URL to use and a handle giving back the request it received
+    fn serve(parts: Vec<String>) -> (String, thread::JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!(
+            "http://{}/v1/chat/completions",
+            listener.local_addr().unwrap()
+        );
+
+        let handle = thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+
+            let mut request = String::new();
+            let mut content_length = 0;
+
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+
+                if let Some(length) = line.to_lowercase().strip_prefix("content-length:") {
+                    content_length = length.trim().parse().unwrap();
+                }
+
+                request.push_str(&line);
+
+                if line == "\r\n" {
+                    break;
+                }
+            }
+
+            let mut body = vec![0; content_length];
+            reader.read_exact(&mut body).unwrap();
+            request.push_str(&String::from_utf8(body).unwrap());
+
+            // The client may hang up part way through, so write errors are ignored
+            let mut stream = stream;
+
+            for part in parts {
+                let _ = stream.write_all(part.as_bytes());
+                let _ = stream.flush();
+                thread::sleep(Duration::from_millis(20));
+            }
+
+            request
+        });
+
+        (url, handle)
+    }
+
+    // Answer one request with a chunked body
+    fn serve_chunked(chunks: Vec<String>) -> (String, thread::JoinHandle<String>) {
+        let mut parts = vec!["HTTP/1.1 200 OK\r\nTransfer-Encoding: chunked\r\n\r\n".to_string()];
+        parts.extend(
+            chunks
+                .iter()
+                .map(|chunk| format!("{:x}\r\n{}\r\n", chunk.len(), chunk)),
+        );
+        parts.push("0\r\n\r\n".to_string());
+
+        serve(parts)
+    }
+
+    // Answer one request with a Content-Length body, split into the given pieces
+    fn serve_with_length(
+        status: &str,
+        length: usize,
+        pieces: &[&str],
+    ) -> (String, thread::JoinHandle<String>) {
+        let mut parts = vec![format!(
+            "HTTP/1.1 {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\n\r\n",
+            status, length
+        )];
+        parts.extend(pieces.iter().map(|piece| piece.to_string()));
+
+        serve(parts)
+    }
+
+    fn provider(url: String) -> HttpProvider {
+        HttpProvider::new(
+            url,
+            "llama3".into(),
+            Some("secret".into()),
+            Duration::from_secs(5),
+        )
+    }
+
+    #[test]
+    fn http_provider_streams_server_sent_events() {
+        // Chunks split mid-line, as a real server is free to do
+        let (url, server) = serve_chunked(vec![
+            "data: {\"choices\":[{\"delta\":{\"content\":\"Good \"}}]}\n\ndata: {\"choi".into(),
+            "ces\":[{\"delta\":{\"content\":\"day\"}}]}\n\n".into(),
+            "data: {\"choices\":[{\"delta\":{\"content\":\" to you.\"}}]}\n\n".into(),
+            "data: [DONE]\n\n".into(),
+        ]);
+
+        let mut tokens = Vec::new();
+        let reply = provider(url)
+            .stream_reply(&context(), &memory(), &mut |token| {
+                tokens.push(token.to_string());
+                true
+            })
+            .unwrap();
+
+        assert_eq!(tokens, ["Good ", "day", " to you."]);
+        assert_eq!(reply, "Good day to you.");
+
+        let request = server.join().unwrap();
+        assert!(request.starts_with("POST /v1/chat/completions HTTP/1.1\r\n"));
+        assert!(request.contains("Authorization: Bearer secret\r\n"));
+        assert!(request.contains("\"stream\":true"));
+        assert!(request.contains("Hello there"));
+    }
+
+    #[test]
+    fn http_provider_streams_ndjson_until_done() {
+        let (url, server) = serve_chunked(vec![
+            "{\"message\":{\"content\":\"Welcome\"},\"done\":false}\n".into(),
+            "{\"message\":{\"content\":\", traveller\"},\"done\":false}\n".into(),
+            "{\"message\":{\"content\":\"\"},\"done\":true}\n".into(),
+            "{\"message\":{\"content\":\"ignored\"},\"done\":false}\n".into(),
+        ]);
+
+        let mut tokens = Vec::new();
+        let reply = provider(url)
+            .stream_reply(&context(), &memory(), &mut |token| {
+                tokens.push(token.to_string());
+                true
+            })
+            .unwrap();
+
+        assert_eq!(tokens, ["Welcome", ", traveller"]);
+        assert_eq!(reply, "Welcome, traveller");
+
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn http_provider_stops_when_told_to() {
+        let (url, server) = serve_chunked(vec![
+            "{\"message\":{\"content\":\"One\"}}\n".into(),
+            "{\"message\":{\"content\":\"Two\"}}\n".into(),
+        ]);
+
+        let mut tokens = Vec::new();
+        provider(url)
+            .stream_reply(&context(), &memory(), &mut |token| {
+                tokens.push(token.to_string());
+                false
+            })
+            .unwrap();
+
+        assert_eq!(tokens, ["One"]);
+
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn http_provider_completes_from_a_body_that_arrives_in_pieces() {
+        let body = "{\"choices\":[{\"message\":{\"content\":\" Well met. \"}}]}";
+        let (head, tail) = body.split_at(20);
+        let (url, server) = serve_with_length("200 OK", body.len(), &[head, tail]);
+
+        let reply = provider(url).complete("Greet me".into()).unwrap();
+        assert_eq!(reply, "Well met.");
+
+        let request = server.join().unwrap();
+        assert!(request.starts_with("POST /v1/chat/completions HTTP/1.1\r\n"));
+        assert!(request.contains("\"stream\":false"));
+        assert!(request.contains("Greet me"));
+    }
+
+    #[test]
+    fn http_provider_reads_no_further_than_the_content_length() {
+        let body = "{\"response\":\"Aye\"}";
+        let (url, server) = serve_with_length(
+            "200 OK",
+            body.len(),
+            &[&format!(
+                "{}trailing bytes that aren't part of the body",
+                body
+            )],
+        );
+
+        assert_eq!(provider(url).complete("Well?".into()).unwrap(), "Aye");
+
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn http_provider_fails_when_the_body_ends_early() {
+        let (url, server) = serve_with_length("200 OK", 100, &["{\"response\":", "\"cut o"]);
+
+        let error = provider(url).complete("Well?".into()).unwrap_err();
+        assert!(error.to_string().contains("bytes short"), "{}", error);
+
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn http_provider_reports_error_statuses_with_their_body() {
+        let (url, server) = serve_with_length("503 Service Unavailable", 10, &["model", " busy"]);
+
+        let error = provider(url).complete("Well?".into()).unwrap_err();
+        assert_eq!(error.to_string(), "HTTP 503: model busy");
+
+        server.join().unwrap();
+
+        // Even when the error body is cut short
+        let (url, server) = serve_with_length("500 Internal Server Error", 50, &["oh no"]);
+
+        let error = provider(url).complete("Well?".into()).unwrap_err();
+        assert_eq!(error.to_string(), "HTTP 500: oh no");
+
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn http_provider_times_out_on_a_silent_server() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/", listener.local_addr().unwrap());
+
+        // Accept the connection but never answer
+        let server = thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            thread::sleep(Duration::from_millis(500));
+            drop(stream);
+        });
+
+        let provider = HttpProvider::new(url, "llama3".into(), None, Duration::from_millis(100));
+        let result = provider.stream_reply(&context(), &memory(), &mut |_| true);
+
+        assert!(result.is_err());
+
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn mock_provider_cycles_through_its_replies() {
+        let provider = MockProvider::new(vec!["First.".into(), "Second.".into()]);
+
+        let replies: Vec<String> = (0..3)
+            .map(|_| provider.reply(&context(), &memory()).unwrap())
+            .collect();
+        assert_eq!(replies, ["First.", "Second.", "First."]);
+
+        // Streaming falls back to handing over the whole reply at once
+        let mut tokens = Vec::new();
+        let reply = provider
+            .stream_reply(&context(), &memory(), &mut |token| {
+                tokens.push(token.to_string());
+                true
+            })
+            .unwrap();
+        assert_eq!(reply, "Second.");
+        assert_eq!(tokens, ["Second."]);
+
+        let summary = provider
+            .summarize(&context(), "Met once.", &memory().transcript)
+            .unwrap();
+        assert_eq!(summary, "Met once. [1 lines summarized]");
+    }
+
+    #[test]
+    fn mock_provider_without_replies_errors() {
+        let provider = MockProvider::new(Vec::new());
+
+        assert!(provider.reply(&context(), &memory()).is_err());
+    }
+}
@@ -0,0 +1,111 @@
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::PathBuf;
+
+// Struct for game configuration, loaded from config.json if present
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Config {
+    pub dialogue_provider: ProviderType,
+    pub narrator_provider: ProviderType,
+    pub ai_url: String,
+    pub ai_model: String,
+    pub ai_api_key: Option<String>,
+    pub ai_timeout_secs: u64,
+    pub dialogue_memory_chars: usize,
+    pub dialogue_keep_recent: usize,
+    pub starting_gold: u32,
+    pub autosave_on_travel: bool,
+    pub autosave_on_rest: bool,
+    pub autosave_on_quit: bool,
+    pub autosave_interval_secs: u64,
+    pub save_backups: usize,
+    pub save_format: SaveFormat,
+}
+
+// Functions for Config
+impl Config {
+    // Load config from JSON, falls back to defaults if missing or invalid
+    pub fn load() -> Self {
+        let config_path = PathBuf::from("config.json");
+
+        match fs::read_to_string(&config_path) {
+            Ok(config_data) => match serde_json::from_str::<Self>(&config_data) {
+                Ok(config) => {
+                    config.check_ai_url();
+                    config
+                }
+                Err(e) => {
+                    log::error!("Failed to parse config, using defaults: {}", e);
+                    Self::default()
+                }
+            },
+            Err(_) => {
+                log::info!("No config found at {:?}, using defaults.", config_path);
+                Self::default()
+            }
+        }
+    }
+
+    // Report an ai_url the HTTP provider can't use when the config is loaded, the same error is shown in conversations
+    fn check_ai_url(&self) {
+        let uses_http = matches!(self.dialogue_provider, ProviderType::Http)
+            || matches!(self.narrator_provider, ProviderType::Http);
+
+        if uses_http {
+            if let Err(e) = crate::ai::http::validate_url(&self.ai_url) {
+                log::error!("Invalid ai_url in config: {}", e);
+            }
+        }
+    }
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            dialogue_provider: ProviderType::Template,
+            narrator_provider: ProviderType::Template,
+            ai_url: "http://localhost:11434/v1/chat/completions".into(),
+            ai_model: "llama3".into(),
+            ai_api_key: None,
+            ai_timeout_secs: 60,
+            dialogue_memory_chars: 4000,
+            dialogue_keep_recent: 8,
+            starting_gold: crate::entities::player::STARTING_GOLD,
+            autosave_on_travel: true,
+            autosave_on_rest: true,
+            autosave_on_quit: true,
+            autosave_interval_secs: 300,
+            save_backups: 3,
+            save_format: SaveFormat::Json,
+        }
+    }
+}
+
+// Enum for dialogue provider types
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ProviderType {
+    Template,
+    Http,
+    Mock,
+}
+
+// Enum for how save files are written
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SaveFormat {
+    Json,
+    Binary,
+}
+
+// Functions for Save Format
+impl SaveFormat {
+    // File extension used for saves in this format
+    pub fn extension(&self) -> &'static str {
+        match self {
+            SaveFormat::Json => "json",
+            SaveFormat::Binary => "sav",
+        }
+    }
+}
@@ -0,0 +1,472 @@
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::str::FromStr;
+
+use crate::entities::character::{Attribute, Attributes};
+
+// Const for the attribute value that gives no modifier to a skill check
+const AVERAGE_ATTRIBUTE: i32 = 5;
+
+// Consts for the largest dice notation accepted, so a roll can't run away or overflow its total
+const MAX_DICE: u32 = 100;
+const MAX_SIDES: u32 = 1000;
+const MAX_MODIFIER: i32 = 1000;
+
+// Struct for dice in notation like 2d6+1
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DiceRoll {
+    pub count: u32,
+    pub sides: u32,
+    pub modifier: i32,
+}
+
+// Functions for Dice Roll
+impl DiceRoll {
+    // Create a new Dice Roll
+    pub const fn new(count: u32, sides: u32, modifier: i32) -> Self {
+        Self {
+            count,
+            sides,
+            modifier,
+        }
+    }
+}
+
+// Parse dice notation: an optional count, d, the sides and an optional + or - modifier
+impl FromStr for DiceRoll {
+    type Err = DiceError;
+
+    fn from_str(notation: &str) -> Result<Self, Self::Err> {
+        let error = |message: &str| DiceError {
+            notation: notation.to_string(),
+            message: message.to_string(),
+        };
+
+        let compact: String = notation
+            .chars()
+            .filter(|c| !c.is_whitespace())
+            .collect::<String>()
+            .to_lowercase();
+
+        let (count, rest) = compact
+            .split_once('d')
+            .ok_or_else(|| error("expected a d between the count and the sides"))?;
+
+        let count = match count {
+            "" => 1,
+            count => count
+                .parse()
+                .map_err(|_| error("the count must be a whole number"))?,
+        };
+
+        let (sides, modifier) = match rest.find(['+', '-']) {
+            Some(index) => {
+                let modifier: i32 = rest[index..]
+                    .parse()
+                    .map_err(|_| error("the modifier must be a whole number"))?;
+
+                (&rest[..index], modifier)
+            }
+            None => (rest, 0),
+        };
+
+        let sides = sides
+            .parse()
+            .map_err(|_| error("the sides must be a whole number"))?;
+
+        if count == 0 {
+            return Err(error("there must be at least one die"));
+        }
+
+        if sides == 0 {
+            return Err(error("dice must have at least one side"));
+        }
+
+        if count > MAX_DICE {
+            return Err(error(&format!("at most {} dice can be rolled", MAX_DICE)));
+        }
+
+        if sides > MAX_SIDES {
+            return Err(error(&format!("dice can have at most {} sides", MAX_SIDES)));
+        }
+
+        if modifier.unsigned_abs() > MAX_MODIFIER.unsigned_abs() {
+            return Err(error(&format!(
+                "the modifier must be between -{} and {}",
+                MAX_MODIFIER, MAX_MODIFIER
+            )));
+        }
+
+        Ok(Self::new(count, sides, modifier))
+    }
+}
+
+impl std::fmt::Display for DiceRoll {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}d{}", self.count, self.sides)?;
+
+        match self.modifier {
+            0 => Ok(()),
+            modifier if modifier > 0 => write!(f, "+{}", modifier),
+            modifier => write!(f, "{}", modifier),
+        }
+    }
+}
+
+// Struct for dice notation that couldn't be parsed
+#[derive(Debug)]
+pub struct DiceError {
+    notation: String,
+    message: String,
+}
+
+impl std::fmt::Display for DiceError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Invalid dice `{}`: {}", self.notation, self.message)
+    }
+}
+
+impl std::error::Error for DiceError {}
+
+// Enum for rolling twice and keeping the better or worse result
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RollMode {
+    Normal,
+    Advantage,
+    Disadvantage,
+}
+
+// Struct for the result of a roll, with every die so the UI can show them
+#[derive(Debug, Clone)]
+pub struct RollResult {
+    pub dice: DiceRoll,
+    pub mode: RollMode,
+    pub rolls: Vec<u32>,
+    pub discarded: Option<Vec<u32>>,
+    pub total: i32,
+}
+
+// Functions for Roll Result
+impl RollResult {
+    // Describe the roll for the player, e.g. "2d6+1: 3 + 5 + 1 = 9"
+    pub fn describe(&self) -> String {
+        let rolls: Vec<String> = self.rolls.iter().map(|roll| roll.to_string()).collect();
+        let modifier = match self.dice.modifier {
+            0 => String::new(),
+            modifier if modifier > 0 => format!(" + {}", modifier),
+            modifier => format!(" - {}", -modifier),
+        };
+
+        let mut description = format!(
+            "{}: {}{} = {}",
+            self.dice,
+            rolls.join(" + "),
+            modifier,
+            self.total
+        );
+
+        if let Some(discarded) = &self.discarded {
+            let discarded: Vec<String> = discarded.iter().map(|roll| roll.to_string()).collect();
+
+            description.push_str(&format!(
+                " ({}, dropped {})",
+                if self.mode == RollMode::Advantage {
+                    "advantage"
+                } else {
+                    "disadvantage"
+                },
+                discarded.join(" + ")
+            ));
+        }
+
+        description
+    }
+}
+
+// Struct for the result of a skill check
+#[derive(Debug, Clone)]
+pub struct CheckResult {
+    pub attribute: Attribute,
+    pub roll: u32,
+    pub modifier: i32,
+    pub total: i32,
+    pub dc: i32,
+    pub margin: i32,
+    pub success: bool,
+    pub mode: RollMode,
+}
+
+// Functions for Check Result
+impl CheckResult {
+    // Describe the check for the player, e.g. "Strength check: 14 + 1 = 15 vs DC 12, passed by 3"
+    pub fn describe(&self) -> String {
+        let mode = match self.mode {
+            RollMode::Normal => "",
+            RollMode::Advantage => " with advantage",
+            RollMode::Disadvantage => " with disadvantage",
+        };
+
+        let outcome = if self.success {
+            format!("passed by {}", self.margin)
+        } else {
+            format!("failed by {}", -self.margin)
+        };
+
+        format!(
+            "{:?} check{}: {} {} {} = {} vs DC {}, {}",
+            self.attribute,
+            mode,
+            self.roll,
+            if self.modifier < 0 { "-" } else { "+" },
+            self.modifier.abs(),
+            self.total,
+            self.dc,
+            outcome
+        )
+    }
+}
+
+// Struct for Dice, every roll in the game comes from one of these so it can be seeded
+pub struct Dice {
+    rng: StdRng,
+}
+
+// Functions for Dice
+impl Dice {
+    // Create new Dice, the same seed always gives the same rolls
+    pub fn new(seed: u64) -> Self {
+        Self {
+            rng: StdRng::seed_from_u64(seed),
+        }
+    }
+
+    // Create new Dice with a random seed
+    pub fn from_entropy() -> Self {
+        Self::new(rand::random())
+    }
+
+    // Create new Dice seeded from these ones, for rolls made on another thread
+    pub fn fork(&mut self) -> Self {
+        Self::new(self.rng.random())
+    }
+
+    // Roll a single die
+    pub fn die(&mut self, sides: u32) -> u32 {
+        self.rng.random_range(1..=sides.max(1))
+    }
+
+    // Roll dice and add the modifier
+    pub fn roll(&mut self, dice: DiceRoll) -> RollResult {
+        self.roll_with(dice, RollMode::Normal)
+    }
+
+    // Roll dice, rolling twice with advantage or disadvantage and keeping the higher or lower total
+    pub fn roll_with(&mut self, dice: DiceRoll, mode: RollMode) -> RollResult {
+        let mut rolls = self.roll_once(dice);
+        let mut discarded = None;
+
+        if mode != RollMode::Normal {
+            let other = self.roll_once(dice);
+            let sum = |rolls: &[u32]| rolls.iter().sum::<u32>();
+
+            let keep_other = match mode {
+                RollMode::Advantage => sum(&other) > sum(&rolls),
+                _ => sum(&other) < sum(&rolls),
+            };
+
+            discarded = Some(if keep_other {
+                std::mem::replace(&mut rolls, other)
+            } else {
+                other
+            });
+        }
+
+        let total = rolls.iter().sum::<u32>() as i32 + dice.modifier;
+
+        RollResult {
+            dice,
+            mode,
+            rolls,
+            discarded,
+            total,
+        }
+    }
+
+    // Roll a d20 plus the attribute's modifier against a difficulty class
+    pub fn skill_check(
+        &mut self,
+        attributes: &Attributes,
+        attribute: Attribute,
+        dc: i32,
+        mode: RollMode,
+    ) -> CheckResult {
+        let modifier = attribute_modifier(attributes.get(attribute));
+        let result = self.roll_with(DiceRoll::new(1, 20, modifier), mode);
+
+        let roll = result.rolls[0];
+        let margin = result.total - dc;
+
+        CheckResult {
+            attribute,
+            roll,
+            modifier,
+            total: result.total,
+            dc,
+            margin,
+            success: margin >= 0,
+            mode,
+        }
+    }
+
+    fn roll_once(&mut self, dice: DiceRoll) -> Vec<u32> {
+        (0..dice.count).map(|_| self.die(dice.sides)).collect()
+    }
+}
+
+// The modifier an attribute adds to skill checks, average attributes add nothing
+pub fn attribute_modifier(value: u32) -> i32 {
+    i32::try_from(value)
+        .unwrap_or(i32::MAX)
+        .saturating_sub(AVERAGE_ATTRIBUTE)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entities::character::{ATTRIBUTE_MAX, ATTRIBUTE_MIN};
+
+    #[test]
+    fn parses_dice_notation() {
+        assert_eq!("2d6+1".parse::<DiceRoll>().unwrap(), DiceRoll::new(2, 6, 1));
+        assert_eq!("d20".parse::<DiceRoll>().unwrap(), DiceRoll::new(1, 20, 0));
+        assert_eq!(
+            "3d4-2".parse::<DiceRoll>().unwrap(),
+            DiceRoll::new(3, 4, -2)
+        );
+        assert_eq!(
+            " 2 D 6 + 1 ".parse::<DiceRoll>().unwrap(),
+            DiceRoll::new(2, 6, 1)
+        );
+    }
+
+    #[test]
+    fn displays_as_it_parses() {
+        for notation in ["2d6+1", "1d20", "3d4-2"] {
+            assert_eq!(notation.parse::<DiceRoll>().unwrap().to_string(), notation);
+        }
+    }
+
+    #[test]
+    fn rejects_bad_notation() {
+        let error = |notation: &str| notation.parse::<DiceRoll>().unwrap_err().to_string();
+
+        assert_eq!(
+            error("0d6"),
+            "Invalid dice `0d6`: there must be at least one die"
+        );
+        assert_eq!(
+            error("2d0"),
+            "Invalid dice `2d0`: dice must have at least one side"
+        );
+        assert_eq!(
+            error("abc"),
+            "Invalid dice `abc`: expected a d between the count and the sides"
+        );
+        assert_eq!(
+            error("2d"),
+            "Invalid dice `2d`: the sides must be a whole number"
+        );
+        assert_eq!(
+            error("2d6+1+1"),
+            "Invalid dice `2d6+1+1`: the modifier must be a whole number"
+        );
+    }
+
+    #[test]
+    fn rejects_notation_that_would_overflow() {
+        let error = |notation: &str| notation.parse::<DiceRoll>().unwrap_err().to_string();
+
+        assert_eq!(
+            error("99999999999d6"),
+            "Invalid dice `99999999999d6`: the count must be a whole number"
+        );
+        assert_eq!(
+            error("2d6+99999999999"),
+            "Invalid dice `2d6+99999999999`: the modifier must be a whole number"
+        );
+        assert_eq!(
+            error("4000000000d6"),
+            "Invalid dice `4000000000d6`: at most 100 dice can be rolled"
+        );
+        assert_eq!(
+            error("1d4294967295"),
+            "Invalid dice `1d4294967295`: dice can have at most 1000 sides"
+        );
+        assert_eq!(
+            error("1d6-2147483648"),
+            "Invalid dice `1d6-2147483648`: the modifier must be between -1000 and 1000"
+        );
+    }
+
+    #[test]
+    fn rolls_stay_in_range() {
+        let mut dice = Dice::new(1);
+        let roll = DiceRoll::new(3, 4, -2);
+
+        for _ in 0..200 {
+            let result = dice.roll(roll);
+
+            assert_eq!(result.rolls.len(), 3);
+            assert!(result.rolls.iter().all(|&die| (1..=4).contains(&die)));
+            assert_eq!(result.total, result.rolls.iter().sum::<u32>() as i32 - 2);
+            assert!(result.discarded.is_none());
+        }
+    }
+
+    #[test]
+    fn advantage_keeps_the_higher_roll() {
+        let mut dice = Dice::new(2);
+
+        for _ in 0..200 {
+            let result = dice.roll_with(DiceRoll::new(1, 20, 0), RollMode::Advantage);
+            let discarded = result.discarded.as_ref().unwrap();
+
+            assert!(result.rolls[0] >= discarded[0]);
+        }
+    }
+
+    #[test]
+    fn disadvantage_keeps_the_lower_roll() {
+        let mut dice = Dice::new(3);
+
+        for _ in 0..200 {
+            let result = dice.roll_with(DiceRoll::new(2, 6, 0), RollMode::Disadvantage);
+            let discarded = result.discarded.as_ref().unwrap();
+
+            assert!(result.rolls.iter().sum::<u32>() <= discarded.iter().sum::<u32>());
+        }
+    }
+
+    #[test]
+    fn the_same_seed_gives_the_same_rolls() {
+        let roll = |seed| {
+            let mut dice = Dice::new(seed);
+
+            (0..50)
+                .map(|_| dice.roll(DiceRoll::new(2, 6, 1)).total)
+                .collect::<Vec<i32>>()
+        };
+
+        assert_eq!(roll(42), roll(42));
+        assert_ne!(roll(42), roll(43));
+    }
+
+    #[test]
+    fn attribute_modifiers_cover_the_attribute_range() {
+        assert_eq!(attribute_modifier(AVERAGE_ATTRIBUTE as u32), 0);
+        assert_eq!(attribute_modifier(ATTRIBUTE_MIN), -2);
+        assert_eq!(attribute_modifier(ATTRIBUTE_MAX), 3);
+        assert_eq!(attribute_modifier(0), -AVERAGE_ATTRIBUTE);
+        assert_eq!(attribute_modifier(u32::MAX), i32::MAX - AVERAGE_ATTRIBUTE);
+    }
+}
@@ -1,6 +1,10 @@
 use crossterm::event::{self, Event, KeyCode};
 use std::io;
 use std::time::Duration;
+use strum::IntoEnumIterator;
+
+// Const for the Strength check to avoid being chilled when travelling through bad weather
+const CHILL_DC: i32 = 10;
 
 // Struct for Event Handler
 pub struct EventHandler {}
@@ -15,23 +19,25 @@ impl EventHandler {
         match managers.state_manager.current_state {
             // New Game (Enter Name)
             super::states::StateType::Name => {
-                if let Event::Key(key) = event::read()? {
-                    match key.code {
-                        KeyCode::Char(c) => ui_components.popup.input.push(c),
-                        KeyCode::Backspace => {
-                            ui_components.popup.input.pop();
-                        }
-                        KeyCode::Enter => {
-                            managers.state_manager.current_state =
-                                super::states::StateType::NameConfirm;
-                        }
-                        KeyCode::Esc => {
-                            ui_components.popup.input.clear();
+                if event::poll(Duration::ZERO)? {
+                    if let Event::Key(key) = event::read()? {
+                        match key.code {
+                            KeyCode::Char(c) => ui_components.popup.input.push(c),
+                            KeyCode::Backspace => {
+                                ui_components.popup.input.pop();
+                            }
+                            KeyCode::Enter => {
+                                managers.state_manager.current_state =
+                                    super::states::StateType::NameConfirm;
+                            }
+                            KeyCode::Esc => {
+                                ui_components.popup.input.clear();
 
-                            managers.state_manager.current_state =
-                                super::states::StateType::MainMenu;
+                                managers.state_manager.current_state =
+                                    super::states::StateType::MainMenu;
+                            }
+                            _ => {}
                         }
-                        _ => {}
                     }
                 }
 
@@ -39,33 +45,118 @@ impl EventHandler {
             }
             // New Game (Confirm Name)
             super::states::StateType::NameConfirm => {
-                if let Event::Key(key) = event::read()? {
-                    match key.code {
-                        KeyCode::Enter => {
-                            // Start the game
-                            match start_game(managers, ui_components) {
-                                Ok(()) => {
-                                    ui_components.popup.input.clear();
-
-                                    managers.state_manager.current_state =
-                                        super::states::StateType::Game;
-                                }
-                                Err(e) => {
-                                    log::error!("Failed to load game assets: {}", e);
+                if event::poll(Duration::ZERO)? {
+                    if let Event::Key(key) = event::read()? {
+                        match key.code {
+                            // Move on to creating the character
+                            KeyCode::Enter => {
+                                managers.world_manager.character_creation =
+                                    crate::entities::character::CharacterCreation::new(
+                                        rand::random(),
+                                    );
+
+                                managers.state_manager.current_state =
+                                    super::states::StateType::CreateRace;
+                                ui_components.menu.selected_index = 0;
+                            }
+                            KeyCode::Esc => {
+                                ui_components.popup.input.clear();
+
+                                managers.state_manager.current_state =
+                                    super::states::StateType::Name;
+                            }
+                            _ => {}
+                        }
+                    }
+                }
+
+                Ok(true)
+            }
+            // Conversation (never blocks, the NPC may be replying in the background)
+            super::states::StateType::Conversation => {
+                if event::poll(Duration::ZERO)? {
+                    if let Event::Key(key) = event::read()? {
+                        match key.code {
+                            KeyCode::Char(c) => ui_components.viewport.input.push(c),
+                            KeyCode::Backspace => {
+                                ui_components.viewport.input.pop();
+                            }
+                            KeyCode::Enter
+                                if !ui_components.viewport.input.trim().is_empty()
+                                    && !managers.dialogue_manager.is_generating() =>
+                            {
+                                let message = std::mem::take(&mut ui_components.viewport.input);
 
-                                    ui_components.popup.input.clear();
+                                if let Err(e) = send_message(managers, message.clone()) {
+                                    log::error!("Failed to send message: {}", e);
 
-                                    managers.state_manager.current_state =
-                                        super::states::StateType::GameInitError;
+                                    ui_components.viewport.input = message;
                                 }
                             }
+                            KeyCode::Esc if managers.dialogue_manager.is_generating() => {
+                                managers.dialogue_manager.cancel();
+                            }
+                            KeyCode::Esc => {
+                                managers.dialogue_manager.end();
+                                ui_components.viewport.input.clear();
+
+                                managers.state_manager.current_state =
+                                    super::states::StateType::Room;
+                            }
+                            _ => {}
                         }
-                        KeyCode::Esc => {
-                            ui_components.popup.input.clear();
+                    }
+                }
+
+                Ok(true)
+            }
+            // Load Game (Rename Slot)
+            super::states::StateType::LoadSlotRename => {
+                if event::poll(Duration::ZERO)? {
+                    if let Event::Key(key) = event::read()? {
+                        match key.code {
+                            KeyCode::Char(c) => ui_components.popup.input.push(c),
+                            KeyCode::Backspace => {
+                                ui_components.popup.input.pop();
+                            }
+                            KeyCode::Enter => {
+                                let new_name = std::mem::take(&mut ui_components.popup.input);
+                                let slot = managers
+                                    .save_manager
+                                    .selected()
+                                    .map(|slot| slot.name.clone());
 
-                            managers.state_manager.current_state = super::states::StateType::Name;
+                                if let Some(slot) = slot {
+                                    match managers.save_manager.rename_slot(&slot, &new_name) {
+                                        Ok(new_slot) => {
+                                            managers.save_manager.selected_slot = managers
+                                                .save_manager
+                                                .slots
+                                                .iter()
+                                                .position(|slot| slot.name == new_slot);
+
+                                            ui_components.viewport.notice =
+                                                Some(format!("Renamed to {}.", new_slot));
+                                        }
+                                        Err(e) => {
+                                            log::error!("Failed to rename save {}: {}", slot, e);
+
+                                            ui_components.viewport.notice = Some(e.to_string());
+                                        }
+                                    }
+                                }
+
+                                managers.state_manager.current_state =
+                                    super::states::StateType::LoadSlot;
+                            }
+                            KeyCode::Esc => {
+                                ui_components.popup.input.clear();
+
+                                managers.state_manager.current_state =
+                                    super::states::StateType::LoadSlot;
+                            }
+                            _ => {}
                         }
-                        _ => {}
                     }
                 }
 
@@ -73,9 +164,11 @@ impl EventHandler {
             }
             // Save Game
             super::states::StateType::GameSaveSuccess | super::states::StateType::GameSaveError => {
-                if let Event::Key(key) = event::read()? {
-                    if key.code == KeyCode::Enter {
-                        managers.state_manager.current_state = super::states::StateType::Game;
+                if event::poll(Duration::ZERO)? {
+                    if let Event::Key(key) = event::read()? {
+                        if key.code == KeyCode::Enter {
+                            managers.state_manager.current_state = super::states::StateType::Game;
+                        }
                     }
                 }
 
@@ -83,9 +176,12 @@ impl EventHandler {
             }
             // Load Game (Error) and Initialize Game (Error)
             super::states::StateType::GameLoadError | super::states::StateType::GameInitError => {
-                if let Event::Key(key) = event::read()? {
-                    if key.code == KeyCode::Enter {
-                        managers.state_manager.current_state = super::states::StateType::MainMenu;
+                if event::poll(Duration::ZERO)? {
+                    if let Event::Key(key) = event::read()? {
+                        if key.code == KeyCode::Enter {
+                            managers.state_manager.current_state =
+                                super::states::StateType::MainMenu;
+                        }
                     }
                 }
 
@@ -96,12 +192,41 @@ impl EventHandler {
                 if event::poll(Duration::ZERO)? {
                     if let Event::Key(key) = event::read()? {
                         match key.code {
+                            KeyCode::Up | KeyCode::Down | KeyCode::Left | KeyCode::Right
+                                if matches!(
+                                    managers.state_manager.current_state,
+                                    super::states::StateType::TownMap
+                                ) =>
+                            {
+                                move_in_town(managers, ui_components, key.code);
+                            }
+                            KeyCode::Esc
+                                if matches!(
+                                    managers.state_manager.current_state,
+                                    super::states::StateType::TownMap
+                                ) =>
+                            {
+                                ui_components.viewport.notice = None;
+                                managers.state_manager.current_state =
+                                    super::states::StateType::Explore;
+                                ui_components.menu.selected_index = 0;
+                            }
                             KeyCode::Up => ui_components.menu.previous(),
                             KeyCode::Down => ui_components.menu.next(),
-                            KeyCode::Enter => {
-                                if !select(managers, ui_components)? {
-                                    return Ok(false);
-                                }
+                            KeyCode::Left | KeyCode::Right
+                                if matches!(
+                                    managers.state_manager.current_state,
+                                    super::states::StateType::CreatePointBuy
+                                ) =>
+                            {
+                                adjust_attribute(
+                                    managers,
+                                    ui_components,
+                                    key.code == KeyCode::Right,
+                                );
+                            }
+                            KeyCode::Enter if !select(managers, ui_components)? => {
+                                return Ok(false);
                             }
                             _ => {}
                         }
@@ -126,172 +251,1385 @@ fn select(
                 managers.state_manager.current_state = crate::core::states::StateType::Name;
                 ui_components.menu.selected_index = 0;
             }
-            1 => match load_game(managers) {
-                Ok(()) => {
-                    managers.state_manager.current_state = crate::core::states::StateType::Game;
-                    ui_components.menu.selected_index = 0;
+            1 => {
+                managers.save_manager.refresh_slots();
+                ui_components.viewport.notice = None;
+
+                managers.state_manager.current_state = crate::core::states::StateType::LoadGame;
+                ui_components.menu.selected_index = 0;
+            }
+            2 => return Ok(false),
+            _ => {}
+        },
+        // New Game (choose race)
+        super::states::StateType::CreateRace => {
+            match crate::world::manager::NpcRace::iter().nth(ui_components.menu.selected_index) {
+                Some(race) => {
+                    managers.world_manager.character_creation.race = race;
+                    managers.state_manager.current_state = super::states::StateType::CreateSex;
+                }
+                None => {
+                    ui_components.popup.input.clear();
+
+                    managers.state_manager.current_state = super::states::StateType::MainMenu;
                 }
-                Err(e) => {
-                    log::error!("Failed to load save: {}", e);
+            }
+
+            ui_components.menu.selected_index = 0;
+        }
+        // New Game (choose sex)
+        super::states::StateType::CreateSex => {
+            match crate::world::manager::NpcSex::iter().nth(ui_components.menu.selected_index) {
+                Some(sex) => {
+                    managers.world_manager.character_creation.sex = sex;
+                    managers.state_manager.current_state =
+                        super::states::StateType::CreateBackground;
+                }
+                None => {
+                    managers.state_manager.current_state = super::states::StateType::CreateRace;
+                }
+            }
+
+            ui_components.menu.selected_index = 0;
+        }
+        // New Game (choose background)
+        super::states::StateType::CreateBackground => {
+            match crate::entities::character::Background::iter()
+                .nth(ui_components.menu.selected_index)
+            {
+                Some(background) => {
+                    managers.world_manager.character_creation.background = background;
+                    managers.state_manager.current_state = super::states::StateType::CreateMethod;
+                }
+                None => {
+                    managers.state_manager.current_state = super::states::StateType::CreateSex;
+                }
+            }
 
+            ui_components.menu.selected_index = 0;
+        }
+        // New Game (choose how to set attributes)
+        super::states::StateType::CreateMethod => {
+            match ui_components.menu.selected_index {
+                // Point Buy
+                0 => {
+                    managers.world_manager.character_creation.start_point_buy();
+                    managers.state_manager.current_state = super::states::StateType::CreatePointBuy;
+                }
+                // Roll Dice
+                1 => {
+                    managers.world_manager.character_creation.start_rolling();
+
+                    managers.state_manager.current_state = super::states::StateType::CreateRoll;
+                }
+                _ => {
                     managers.state_manager.current_state =
-                        crate::core::states::StateType::GameLoadError;
+                        super::states::StateType::CreateBackground;
+                }
+            }
+
+            ui_components.viewport.notice = None;
+            ui_components.menu.selected_index = 0;
+        }
+        // New Game (point buy)
+        super::states::StateType::CreatePointBuy => {
+            let attribute_count = crate::entities::character::Attribute::iter().count();
+
+            match ui_components.menu.selected_index {
+                // Enter on an attribute spends a point on it
+                index if index < attribute_count => {
+                    adjust_attribute(managers, ui_components, true);
+                }
+                // Done
+                index if index == attribute_count => {
+                    if managers.world_manager.character_creation.points_left > 0 {
+                        ui_components.viewport.notice = Some(format!(
+                            "You still have {} points to spend.",
+                            managers.world_manager.character_creation.points_left
+                        ));
+                    } else {
+                        ui_components.viewport.notice = None;
+
+                        choose_world(managers, ui_components);
+                    }
+                }
+                _ => {
+                    ui_components.viewport.notice = None;
+
+                    managers.state_manager.current_state = super::states::StateType::CreateMethod;
                     ui_components.menu.selected_index = 0;
                 }
-            },
-            2 => return Ok(false),
-            _ => {}
-        },
-        // Game, Time, and Weather
-        super::states::StateType::Game
-        | super::states::StateType::Time
-        | super::states::StateType::Weather => match ui_components.menu.selected_index {
-            0 => {
-                managers.state_manager.current_state = super::states::StateType::Time;
             }
+        }
+        // New Game (roll dice)
+        super::states::StateType::CreateRoll => match ui_components.menu.selected_index {
+            // Accept
+            0 => choose_world(managers, ui_components),
+            // Reroll
             1 => {
-                managers.state_manager.current_state = super::states::StateType::Weather;
+                managers.world_manager.character_creation.reroll();
             }
-            2 => {
-                managers.state_manager.current_state = super::states::StateType::Travel;
+            _ => {
+                managers.state_manager.current_state = super::states::StateType::CreateMethod;
+                ui_components.menu.selected_index = 0;
             }
-            3 => {
-                match managers.save_manager.save(
-                    &managers.world_manager,
-                    &managers.time_manager,
-                    &managers.weather_manager,
-                ) {
+        },
+        // New Game (select world)
+        super::states::StateType::WorldSelect => {
+            match managers
+                .world_manager
+                .available_worlds
+                .get(ui_components.menu.selected_index)
+            {
+                Some(world_info) => {
+                    managers.world_manager.world_dir = world_info.dir.clone();
+
+                    prepare_new_game(managers, ui_components);
+                }
+                None => {
+                    ui_components.popup.input.clear();
+
+                    managers.state_manager.current_state = super::states::StateType::MainMenu;
+                }
+            }
+
+            ui_components.menu.selected_index = 0;
+        }
+        // New Game (select starting town)
+        super::states::StateType::TownSelect => {
+            let town_names = managers.world_manager.town_names();
+
+            let town_name = match ui_components.menu.selected_index {
+                0 => managers.world_manager.random_town(),
+                index => town_names.get(index - 1).cloned(),
+            };
+
+            match town_name {
+                Some(town_name) => match start_game(managers, ui_components, &town_name) {
                     Ok(()) => {
-                        managers.state_manager.current_state =
-                            super::states::StateType::GameSaveSuccess;
+                        managers.state_manager.current_state = super::states::StateType::Game;
                     }
                     Err(e) => {
-                        log::error!("Failed to save game: {}", e);
+                        log::error!("Failed to start game in {}: {}", town_name, e);
 
                         managers.state_manager.current_state =
-                            super::states::StateType::GameSaveError;
+                            super::states::StateType::GameInitError;
                     }
-                };
+                },
+                None => {
+                    managers.world_manager.clear();
+
+                    managers.state_manager.current_state = super::states::StateType::MainMenu;
+                }
             }
-            4 => {
-                managers.state_manager.current_state = super::states::StateType::GameQuit;
-                ui_components.menu.selected_index = 0;
+
+            ui_components.popup.input.clear();
+            ui_components.menu.selected_index = 0;
+        }
+        // Load Game (list save slots)
+        super::states::StateType::LoadGame => {
+            if ui_components.menu.selected_index < managers.save_manager.slots.len() {
+                managers.save_manager.selected_slot = Some(ui_components.menu.selected_index);
+                managers.state_manager.current_state = super::states::StateType::LoadSlot;
+            } else {
+                managers.state_manager.current_state = super::states::StateType::MainMenu;
             }
-            _ => {}
-        },
-        // Travel
-        super::states::StateType::Travel => match ui_components
-            .menu
-            .menu_options
-            .get(ui_components.menu.selected_index)
-        {
-            Some(selected_option) => {
-                if selected_option == "Back" {
-                    managers.state_manager.current_state = super::states::StateType::Game;
-                    ui_components.menu.selected_index = 0;
-                } else if let Some(player) = managers.world_manager.player.as_mut() {
-                    // Save current town
-                    let current_town = player.town_name.clone();
 
-                    // Change town to new town
-                    player.town_name = selected_option.to_string();
+            ui_components.viewport.notice = None;
+            ui_components.menu.selected_index = 0;
+        }
+        // Load Game (selected slot)
+        super::states::StateType::LoadSlot => {
+            let Some(slot) = managers
+                .save_manager
+                .selected()
+                .map(|slot| slot.name.clone())
+            else {
+                managers.state_manager.current_state = super::states::StateType::LoadGame;
+                return Ok(true);
+            };
+
+            ui_components.viewport.notice = None;
+
+            match ui_components.menu.selected_index {
+                0 => {
+                    let loaded = load_game(managers, &slot, true, None);
+                    finish_load(managers, ui_components, &slot, loaded);
+                }
+                1 => {
+                    ui_components.popup.input = slot;
+                    managers.state_manager.current_state = super::states::StateType::LoadSlotRename;
+                }
+                2 => {
+                    managers.state_manager.current_state = super::states::StateType::LoadSlotDelete;
+                }
+                _ => {
+                    managers.state_manager.current_state = super::states::StateType::LoadGame;
+                }
+            }
+
+            ui_components.menu.selected_index = 0;
+        }
+        // Load Game (checksum mismatch)
+        super::states::StateType::LoadChecksumMismatch => {
+            let Some(slot) = managers
+                .save_manager
+                .selected()
+                .map(|slot| slot.name.clone())
+            else {
+                managers.state_manager.current_state = super::states::StateType::LoadGame;
+                return Ok(true);
+            };
+
+            let loaded = match ui_components.menu.selected_index {
+                0 => Some(load_game(managers, &slot, false, None)),
+                1 => Some(
+                    managers
+                        .save_manager
+                        .restore_backup(&slot)
+                        .and_then(|backup| {
+                            log::info!("Restored save {} from backup {}", slot, backup);
+
+                            load_game(managers, &slot, true, None)
+                        }),
+                ),
+                _ => None,
+            };
+
+            match loaded {
+                Some(loaded) => finish_load(managers, ui_components, &slot, loaded),
+                None => {
+                    managers.state_manager.current_state = super::states::StateType::LoadSlot;
+                }
+            }
+
+            ui_components.menu.selected_index = 0;
+        }
+        // Load Game (world mismatch)
+        super::states::StateType::LoadWorldMismatch => {
+            let (Some(slot), Some(mismatch)) = (
+                managers
+                    .save_manager
+                    .selected()
+                    .map(|slot| slot.name.clone()),
+                managers.save_manager.world_mismatch.take(),
+            ) else {
+                managers.state_manager.current_state = super::states::StateType::LoadGame;
+                return Ok(true);
+            };
+
+            // Loading the matching world is only offered when it's available
+            let index = if mismatch.matching_available {
+                ui_components.menu.selected_index
+            } else {
+                ui_components.menu.selected_index + 1
+            };
+
+            let world_dir = match index {
+                0 => Some(std::path::PathBuf::from(&mismatch.saved.path)),
+                1 => Some(managers.world_manager.world_dir.clone()),
+                _ => None,
+            };
+
+            match world_dir {
+                Some(world_dir) => {
+                    let loaded = load_game(managers, &slot, mismatch.verify, Some(world_dir));
+                    finish_load(managers, ui_components, &slot, loaded);
+                }
+                None => {
+                    managers.state_manager.current_state = super::states::StateType::LoadSlot;
+                }
+            }
+
+            ui_components.menu.selected_index = 0;
+        }
+        // Load Game (confirm delete)
+        super::states::StateType::LoadSlotDelete => {
+            let slot = managers
+                .save_manager
+                .selected()
+                .map(|slot| slot.name.clone());
+
+            match (ui_components.menu.selected_index, slot) {
+                (0, Some(slot)) => {
+                    if let Err(e) = managers.save_manager.delete_slot(&slot) {
+                        log::error!("Failed to delete save {}: {}", slot, e);
 
-                    // Stop time
-                    managers.time_manager.stop();
+                        ui_components.viewport.notice = Some(format!("Couldn't delete {}.", slot));
+                    } else {
+                        ui_components.viewport.notice = Some(format!("Deleted {}.", slot));
+                    }
 
-                    // Save time
-                    let mut time = managers
-                        .time_manager
-                        .time_arc_rwlock
-                        .as_ref()
-                        .and_then(|game_time| game_time.read().ok().map(|t| t.clone()));
+                    managers.state_manager.current_state = super::states::StateType::LoadGame;
+                }
+                _ => {
+                    managers.state_manager.current_state = super::states::StateType::LoadSlot;
+                }
+            }
 
-                    // Advance and restart time
-                    match time.as_mut() {
-                        Some(time_unwrapped) => {
-                            let travel_time = managers
-                                .world_manager
-                                .get_travel_time(&current_town, selected_option);
+            ui_components.menu.selected_index = 0;
+        }
+        // Game, Time, and Weather
+        super::states::StateType::Game
+        | super::states::StateType::Time
+        | super::states::StateType::Weather => {
+            // Reports from the last journey only stay until the player moves on
+            ui_components.viewport.notice = None;
 
-                            time_unwrapped.tick += travel_time;
-                            time_unwrapped.day += time_unwrapped.tick / 900;
-                            time_unwrapped.tick %= 900;
+            match ui_components.menu.selected_index {
+                0 => {
+                    managers.state_manager.current_state = super::states::StateType::Time;
+                }
+                1 => {
+                    managers.state_manager.current_state = super::states::StateType::Weather;
+                }
+                2 => {
+                    managers.state_manager.current_state = super::states::StateType::Travel;
+                    ui_components.menu.selected_index = 0;
+                }
+                3 => {
+                    managers.state_manager.current_state = super::states::StateType::Map;
+                    ui_components.menu.selected_index = 0;
+                }
+                4 => {
+                    managers.state_manager.current_state = super::states::StateType::Explore;
+                    ui_components.menu.selected_index = 0;
+                }
+                5 => {
+                    managers.state_manager.current_state = super::states::StateType::Inventory;
+                    ui_components.menu.selected_index = 0;
+                }
+                6 => {
+                    match save(managers) {
+                        Ok(()) => {
+                            managers.state_manager.current_state =
+                                super::states::StateType::GameSaveSuccess;
+                        }
+                        Err(e) => {
+                            log::error!("Failed to save game: {}", e);
 
-                            managers.time_manager.start(time_unwrapped.clone());
+                            managers.state_manager.current_state =
+                                super::states::StateType::GameSaveError;
                         }
-                        None => {
-                            managers
-                                .time_manager
-                                .start(crate::world::time::GameTime::new());
+                    };
+                }
+                7 => {
+                    managers.state_manager.current_state = super::states::StateType::GameQuit;
+                    ui_components.menu.selected_index = 0;
+                }
+                _ => {}
+            }
+        }
+        // Travel
+        super::states::StateType::Travel => {
+            let destinations = managers.world_manager.travel_destinations();
+
+            match (
+                destinations.get(ui_components.menu.selected_index),
+                managers.world_manager.player.as_ref(),
+            ) {
+                (Some(destination), Some(player)) => {
+                    // Save current town
+                    let current_town = player.town_name.clone();
+
+                    let route = match managers
+                        .world_manager
+                        .plan_route(&current_town, destination)
+                    {
+                        Ok(route) => route,
+                        Err(e) => {
+                            ui_components.viewport.notice = Some(e.to_string());
+
+                            return Ok(true);
                         }
+                    };
+
+                    // Check the player can afford the whole journey, the fare for each leg is paid as it starts
+                    let travel_cost = route.fare(&player.effects);
+
+                    if player.gold < travel_cost {
+                        ui_components.viewport.notice = Some(format!(
+                            "You can't afford the {} gold fare to {}.",
+                            travel_cost, destination
+                        ));
+
+                        return Ok(true);
                     }
 
+                    managers.world_manager.journey = Some(crate::world::journey::Journey::new(
+                        destination.clone(),
+                        route.legs,
+                    ));
+
+                    ui_components.viewport.notice = None;
+
+                    match begin_leg(managers) {
+                        Ok(()) => resume_journey(managers, ui_components),
+                        Err(notice) => end_journey(managers, ui_components, Some(notice)),
+                    }
+                }
+                _ => {
+                    ui_components.viewport.notice = None;
                     managers.state_manager.current_state = super::states::StateType::Game;
                     ui_components.menu.selected_index = 0;
                 }
             }
-            None => {
-                log::error!(
-                    "Failed to find town at selected index: {}",
-                    ui_components.menu.selected_index
-                );
-            }
-        },
-        // Quit Game
-        super::states::StateType::GameQuit => match ui_components.menu.selected_index {
+        }
+        // Journey
+        super::states::StateType::Journey => match ui_components.menu.selected_index {
+            // Stop at Next Town (or Keep Going)
             0 => {
-                managers.time_manager.stop();
-                managers.weather_manager.stop();
+                if let Some(journey) = managers.world_manager.journey.as_mut() {
+                    journey.stop_early = !journey.stop_early;
 
-                managers.state_manager.current_state = crate::core::states::StateType::MainMenu;
-                ui_components.menu.selected_index = 0;
+                    ui_components.viewport.notice = match journey.current_leg() {
+                        Some(leg) if journey.stop_early => {
+                            Some(format!("You'll stop when you reach {}.", leg.to))
+                        }
+                        _ => Some(format!("You press on towards {}.", journey.destination)),
+                    };
+                }
             }
+            // Save
             1 => {
-                managers.state_manager.current_state = crate::core::states::StateType::Game;
+                ui_components.viewport.notice = match save(managers) {
+                    Ok(()) => Some("Game saved.".into()),
+                    Err(e) => {
+                        log::error!("Failed to save game: {}", e);
+
+                        Some("Error saving game!".into())
+                    }
+                };
+            }
+            // Map
+            2 => {
+                ui_components.viewport.notice = None;
+                managers.state_manager.current_state = super::states::StateType::Map;
                 ui_components.menu.selected_index = 0;
             }
             _ => {}
-        }, // All other states
-        _ => {}
-    }
+        },
+        // Map
+        super::states::StateType::Map => {
+            if managers.world_manager.journey.is_some() {
+                resume_journey(managers, ui_components);
 
-    Ok(true)
-}
+                return Ok(true);
+            }
 
-// Start the game
-fn start_game(
-    managers: &mut crate::ui::display::Managers,
-    ui_components: &mut crate::ui::display::UIComponents,
-) -> Result<(), Box<dyn std::error::Error>> {
-    managers.world_manager.clear();
+            // Picking a destination plans the trip on the Travel screen
+            if ui_components.menu.selected_index
+                < managers.world_manager.travel_destinations().len()
+            {
+                managers.state_manager.current_state = super::states::StateType::Travel;
+            } else {
+                managers.state_manager.current_state = super::states::StateType::Game;
+                ui_components.menu.selected_index = 0;
+            }
+        }
+        // Journey (encounter on the road)
+        super::states::StateType::JourneyEncounter => {
+            let world_manager = &mut managers.world_manager;
 
-    managers.world_manager.load_world()?;
+            let (Some(journey), Some(player)) = (
+                world_manager.journey.as_mut(),
+                world_manager.player.as_mut(),
+            ) else {
+                return Ok(true);
+            };
 
-    managers.world_manager.player = Some(crate::entities::player::Player::new(
-        666,
-        ui_components.popup.input.clone(),
-        "Higashi Kawaport".into(),
-    ));
+            let outcome = journey.encounter.as_ref().and_then(|encounter| {
+                encounter.resolve(
+                    ui_components.menu.selected_index,
+                    player,
+                    &mut world_manager.dice,
+                )
+            });
 
-    managers
-        .time_manager
-        .start(crate::world::time::GameTime::new());
-    managers
-        .weather_manager
-        .start(crate::world::weather::GameWeather::new());
+            if let Some(outcome) = outcome {
+                journey.encounter = None;
+                journey.leg_length += outcome.delay;
 
-    Ok(())
+                let mut notice = outcome.notice;
+
+                // Losing gold on the road can leave the player unable to pay for the rest of the journey
+                let next_town = journey.current_leg().map(|leg| leg.to.clone());
+
+                if let (true, Some(next_town)) = (
+                    player.gold < journey.remaining_fare(&player.effects),
+                    next_town,
+                ) {
+                    journey.stop_early = true;
+
+                    notice.push_str(&format!(
+                        " You can't afford to travel further than {}.",
+                        next_town
+                    ));
+                }
+
+                ui_components.viewport.notice = Some(notice);
+
+                resume_journey(managers, ui_components);
+            }
+        }
+        // Explore (select a building)
+        super::states::StateType::Explore => {
+            let (building_id, building_count) = managers
+                .world_manager
+                .current_town()
+                .map(|town| {
+                    (
+                        town.buildings()
+                            .get(ui_components.menu.selected_index)
+                            .map(|building| building.id()),
+                        town.buildings().len(),
+                    )
+                })
+                .unwrap_or_default();
+
+            match building_id {
+                Some(building_id) => enter_building(managers, ui_components, building_id),
+                // Town Map
+                None if ui_components.menu.selected_index == building_count => {
+                    ui_components.viewport.notice = None;
+                    managers.state_manager.current_state = super::states::StateType::TownMap;
+                }
+                None => {
+                    managers.world_manager.current_building_id = None;
+                    managers.state_manager.current_state = super::states::StateType::Game;
+                }
+            }
+
+            ui_components.menu.selected_index = 0;
+        }
+        // Town Map (enter the building the player is standing at)
+        super::states::StateType::TownMap => {
+            let building_id = managers
+                .world_manager
+                .player
+                .as_ref()
+                .and_then(|player| {
+                    managers
+                        .world_manager
+                        .current_town()?
+                        .building_at(player.position)
+                })
+                .map(|building| building.id());
+
+            match building_id {
+                Some(building_id) => {
+                    enter_building(managers, ui_components, building_id);
+                    ui_components.menu.selected_index = 0;
+                }
+                None => {
+                    ui_components.viewport.notice = Some("There's nothing here but street.".into());
+                }
+            }
+        }
+        // Building (select a room)
+        super::states::StateType::Building => {
+            let room_id = managers
+                .world_manager
+                .current_building()
+                .and_then(|building| {
+                    building
+                        .rooms()
+                        .get(ui_components.menu.selected_index)
+                        .map(|room| room.id())
+                });
+
+            match room_id {
+                Some(room_id) => {
+                    managers.world_manager.current_room_id = Some(room_id);
+                    managers.state_manager.current_state = super::states::StateType::Room;
+                }
+                None => {
+                    managers.world_manager.current_building_id = None;
+                    managers.state_manager.current_state = super::states::StateType::Explore;
+                }
+            }
+
+            ui_components.menu.selected_index = 0;
+        }
+        // Room (select an NPC to talk to or a container to search)
+        super::states::StateType::Room => {
+            let index = ui_components.menu.selected_index;
+
+            let (npc_id, container_id) = match managers.world_manager.current_room() {
+                Some(room) => (
+                    room.npcs().get(index).map(|npc| npc.id()),
+                    index
+                        .checked_sub(room.npcs().len())
+                        .and_then(|index| room.containers().get(index))
+                        .map(|container| container.id()),
+                ),
+                None => (None, None),
+            };
+
+            match (npc_id, container_id) {
+                (Some(npc_id), _) => {
+                    managers.dialogue_manager.begin(npc_id);
+                    ui_components.viewport.input.clear();
+                    managers.state_manager.current_state = super::states::StateType::Conversation;
+                }
+                (None, Some(container_id)) => {
+                    managers.world_manager.current_container_id = Some(container_id);
+                    managers.world_manager.open_current_container();
+                    ui_components.viewport.notice = None;
+                    managers.state_manager.current_state = super::states::StateType::Container;
+                    ui_components.menu.selected_index = 0;
+                }
+                (None, None) => {
+                    managers.world_manager.current_room_id = None;
+                    managers.state_manager.current_state = super::states::StateType::Building;
+                    ui_components.menu.selected_index = 0;
+                }
+            }
+        }
+        // Container (take items)
+        super::states::StateType::Container => {
+            let world_manager = &mut managers.world_manager;
+            let index = ui_components.menu.selected_index;
+
+            let contents = world_manager
+                .current_container_id
+                .and_then(|container_id| world_manager.container_contents.get_mut(&container_id));
+
+            match (contents, world_manager.player.as_mut()) {
+                (Some(contents), Some(player)) if index < contents.len() => {
+                    // Take one stack
+                    let stack = &contents[index];
+
+                    match player.inventory.add(stack.item.clone(), stack.quantity) {
+                        Ok(()) => {
+                            let taken = contents.remove(index);
+                            ui_components.viewport.notice =
+                                Some(format!("You take {} x{}.", taken.item.name, taken.quantity));
+                        }
+                        Err(e) => ui_components.viewport.notice = Some(e.to_string()),
+                    }
+                }
+                (Some(contents), Some(player))
+                    if index == contents.len() && !contents.is_empty() =>
+                {
+                    // Take everything that fits
+                    let mut left_behind = Vec::new();
+
+                    for stack in contents.drain(..) {
+                        if player
+                            .inventory
+                            .add(stack.item.clone(), stack.quantity)
+                            .is_err()
+                        {
+                            left_behind.push(stack);
+                        }
+                    }
+
+                    ui_components.viewport.notice = if left_behind.is_empty() {
+                        Some("You take everything.".into())
+                    } else {
+                        Some("You take what you can carry.".into())
+                    };
+
+                    *contents = left_behind;
+                }
+                _ => {
+                    world_manager.current_container_id = None;
+                    ui_components.viewport.notice = None;
+                    managers.state_manager.current_state = super::states::StateType::Room;
+                }
+            }
+
+            ui_components.menu.selected_index = 0;
+        }
+        // Shop
+        super::states::StateType::Shop => {
+            match ui_components.menu.selected_index {
+                0 => {
+                    let day = managers.time_manager.current().map_or(1, |t| t.day);
+                    managers.world_manager.open_current_shop(day);
+
+                    managers.state_manager.current_state = super::states::StateType::ShopBuy;
+                }
+                1 => {
+                    managers.state_manager.current_state = super::states::StateType::ShopSell;
+                }
+                2 => {
+                    managers.state_manager.current_state = super::states::StateType::Building;
+                }
+                _ => {
+                    managers.world_manager.current_building_id = None;
+                    managers.state_manager.current_state = super::states::StateType::Explore;
+                }
+            }
+
+            ui_components.viewport.notice = None;
+            ui_components.menu.selected_index = 0;
+        }
+        // Shop (buy)
+        super::states::StateType::ShopBuy => {
+            match buy_item(managers, ui_components.menu.selected_index) {
+                Some(notice) => ui_components.viewport.notice = Some(notice),
+                None => {
+                    ui_components.viewport.notice = None;
+                    managers.state_manager.current_state = super::states::StateType::Shop;
+                    ui_components.menu.selected_index = 0;
+                }
+            }
+        }
+        // Shop (sell)
+        super::states::StateType::ShopSell => {
+            match sell_item(managers, ui_components.menu.selected_index) {
+                Some(notice) => ui_components.viewport.notice = Some(notice),
+                None => {
+                    ui_components.viewport.notice = None;
+                    managers.state_manager.current_state = super::states::StateType::Shop;
+                    ui_components.menu.selected_index = 0;
+                }
+            }
+        }
+        // Tavern
+        super::states::StateType::Tavern => {
+            match ui_components.menu.selected_index {
+                0 => {
+                    let rested = rest(managers);
+
+                    if rested.is_ok() && managers.config.autosave_on_rest {
+                        autosave(managers);
+                    }
+
+                    ui_components.viewport.notice = Some(rested.unwrap_or_else(|notice| notice));
+                }
+                1 => {
+                    ui_components.viewport.notice = None;
+                    managers.state_manager.current_state = super::states::StateType::TavernRumours;
+                }
+                2 => {
+                    ui_components.viewport.notice = None;
+                    managers.state_manager.current_state = super::states::StateType::Building;
+                }
+                _ => {
+                    ui_components.viewport.notice = None;
+                    managers.world_manager.current_building_id = None;
+                    managers.state_manager.current_state = super::states::StateType::Explore;
+                }
+            }
+
+            ui_components.menu.selected_index = 0;
+        }
+        // Temple
+        super::states::StateType::Temple => {
+            let index = ui_components.menu.selected_index;
+
+            if index < crate::world::temple::SERVICES.len() {
+                ui_components.viewport.notice = Some(temple_service(managers, index));
+            } else if index == crate::world::temple::SERVICES.len() {
+                ui_components.viewport.notice = None;
+                managers.state_manager.current_state = super::states::StateType::Building;
+                ui_components.menu.selected_index = 0;
+            } else {
+                ui_components.viewport.notice = None;
+                managers.world_manager.current_building_id = None;
+                managers.state_manager.current_state = super::states::StateType::Explore;
+                ui_components.menu.selected_index = 0;
+            }
+        }
+        // Tavern (rumours)
+        super::states::StateType::TavernRumours => {
+            managers.state_manager.current_state = super::states::StateType::Tavern;
+            ui_components.menu.selected_index = 0;
+        }
+        // Inventory (select an item to inspect)
+        super::states::StateType::Inventory => {
+            let stack_count = managers
+                .world_manager
+                .player
+                .as_ref()
+                .map(|player| player.inventory.stacks.len())
+                .unwrap_or(0);
+
+            if ui_components.menu.selected_index < stack_count {
+                ui_components.viewport.inventory_index = Some(ui_components.menu.selected_index);
+                managers.state_manager.current_state = super::states::StateType::InventoryItem;
+            } else {
+                ui_components.viewport.inventory_index = None;
+                managers.state_manager.current_state = super::states::StateType::Game;
+            }
+
+            ui_components.menu.selected_index = 0;
+        }
+        // Inventory Item (drop or go back)
+        super::states::StateType::InventoryItem => {
+            let quantity = match ui_components.menu.selected_index {
+                0 => Some(1),
+                1 => Some(u32::MAX),
+                _ => None,
+            };
+
+            if let (Some(quantity), Some(index), Some(player)) = (
+                quantity,
+                ui_components.viewport.inventory_index,
+                managers.world_manager.player.as_mut(),
+            ) {
+                let stack_count = player.inventory.stacks.len();
+
+                if let Some(dropped) = player.inventory.remove(index, quantity) {
+                    log::info!("Dropped {} x{}", dropped.item.name, dropped.quantity);
+                }
+
+                // Stay on the item while some of the stack is left
+                if player.inventory.stacks.len() == stack_count {
+                    return Ok(true);
+                }
+            }
+
+            ui_components.viewport.inventory_index = None;
+            managers.state_manager.current_state = super::states::StateType::Inventory;
+            ui_components.menu.selected_index = 0;
+        }
+        // Quit Game
+        super::states::StateType::GameQuit => match ui_components.menu.selected_index {
+            0 => {
+                if managers.config.autosave_on_quit {
+                    autosave(managers);
+                }
+                managers.save_manager.end_session();
+
+                managers.time_manager.stop();
+                managers.weather_manager.stop();
+
+                managers.state_manager.current_state = crate::core::states::StateType::MainMenu;
+                ui_components.menu.selected_index = 0;
+            }
+            1 => {
+                managers.state_manager.current_state = crate::core::states::StateType::Game;
+                ui_components.menu.selected_index = 0;
+            }
+            _ => {}
+        }, // All other states
+        _ => {}
+    }
+
+    Ok(true)
+}
+
+// Save the game to the current slot
+fn save(managers: &mut crate::ui::display::Managers) -> Result<(), std::io::Error> {
+    managers.save_manager.save(
+        &managers.world_manager,
+        &managers.time_manager,
+        &managers.weather_manager,
+        &managers.dialogue_manager,
+    )
+}
+
+// Save the game without interrupting the player, failures are only logged
+pub fn autosave(managers: &mut crate::ui::display::Managers) {
+    match save(managers) {
+        Ok(()) => log::info!(
+            "Autosaved to {}",
+            managers.save_manager.current_slot.as_deref().unwrap_or("")
+        ),
+        Err(e) => log::error!("Failed to autosave: {}", e),
+    }
+}
+
+// Enter a building in the current town, standing outside it on the town map
+fn enter_building(
+    managers: &mut crate::ui::display::Managers,
+    ui_components: &mut crate::ui::display::UIComponents,
+    building_id: u32,
+) {
+    managers.world_manager.current_building_id = Some(building_id);
+
+    let Some(building) = managers.world_manager.current_building() else {
+        return;
+    };
+
+    // Shops and taverns open straight onto their own screens
+    let building_type = building.building_type().clone();
+    let coords = building.coords();
+
+    if let Some(player) = managers.world_manager.player.as_mut() {
+        player.position = coords;
+    }
+
+    ui_components.viewport.notice = None;
+
+    match building_type {
+        crate::world::manager::BuildingType::Shop => {
+            let day = managers.time_manager.current().map_or(1, |t| t.day);
+            managers.world_manager.open_current_shop(day);
+
+            managers.state_manager.current_state = super::states::StateType::Shop;
+        }
+        crate::world::manager::BuildingType::Temple => {
+            managers.state_manager.current_state = super::states::StateType::Temple;
+        }
+        crate::world::manager::BuildingType::Tavern => {
+            managers.state_manager.current_state = super::states::StateType::Tavern;
+        }
+        crate::world::manager::BuildingType::Residence => {
+            managers.state_manager.current_state = super::states::StateType::Building;
+        }
+    }
+}
+
+// Walk one cell along the town's street grid, stopping at the edge of town
+fn move_in_town(
+    managers: &mut crate::ui::display::Managers,
+    ui_components: &mut crate::ui::display::UIComponents,
+    direction: KeyCode,
+) {
+    let Some(grid_size) = managers
+        .world_manager
+        .current_town()
+        .map(|town| town.grid_size())
+    else {
+        return;
+    };
+
+    let Some(player) = managers.world_manager.player.as_mut() else {
+        return;
+    };
+
+    let (x, y) = player.position;
+
+    let position = match direction {
+        KeyCode::Up => y.checked_sub(1).map(|y| (x, y)),
+        KeyCode::Down => Some((x, y + 1)).filter(|&(_, y)| y < grid_size.1),
+        KeyCode::Left => x.checked_sub(1).map(|x| (x, y)),
+        KeyCode::Right => Some((x + 1, y)).filter(|&(x, _)| x < grid_size.0),
+        _ => None,
+    };
+
+    match position {
+        Some(position) => {
+            player.position = position;
+            ui_components.viewport.notice = None;
+        }
+        None => {
+            ui_components.viewport.notice = Some("That's the edge of town.".into());
+        }
+    }
+}
+
+// Spend or take back a point on the highlighted attribute during point buy
+fn adjust_attribute(
+    managers: &mut crate::ui::display::Managers,
+    ui_components: &mut crate::ui::display::UIComponents,
+    add: bool,
+) {
+    let Some(attribute) =
+        crate::entities::character::Attribute::iter().nth(ui_components.menu.selected_index)
+    else {
+        return;
+    };
+
+    let creation = &mut managers.world_manager.character_creation;
+
+    let changed = if add {
+        creation.add_point(attribute)
+    } else {
+        creation.remove_point(attribute)
+    };
+
+    ui_components.viewport.notice = if changed {
+        None
+    } else if add && creation.points_left == 0 {
+        Some("No points left to spend.".into())
+    } else if add {
+        Some(format!("{:?} is already at its maximum.", attribute))
+    } else {
+        Some(format!("{:?} is already at its minimum.", attribute))
+    };
+}
+
+// Finish character creation, picking a world unless one was given on the command line
+fn choose_world(
+    managers: &mut crate::ui::display::Managers,
+    ui_components: &mut crate::ui::display::UIComponents,
+) {
+    if managers.world_manager.world_chosen {
+        prepare_new_game(managers, ui_components);
+    } else {
+        managers.state_manager.current_state = super::states::StateType::WorldSelect;
+        ui_components.menu.selected_index = 0;
+    }
+}
+
+// Load the chosen world for a new game and move on to picking a starting town
+fn prepare_new_game(
+    managers: &mut crate::ui::display::Managers,
+    ui_components: &mut crate::ui::display::UIComponents,
+) {
+    managers.world_manager.clear();
+    managers.dialogue_manager.clear();
+    managers.narrator_manager.clear();
+
+    match managers.world_manager.load_world() {
+        Ok(()) => {
+            managers.world_manager.world_seed = managers.world_manager.character_creation.seed;
+            managers.world_manager.dice =
+                crate::core::dice::Dice::new(managers.world_manager.world_seed);
+
+            managers.state_manager.current_state = super::states::StateType::TownSelect;
+        }
+        Err(e) => {
+            log::error!("Failed to load game assets: {}", e);
+
+            ui_components.popup.input.clear();
+
+            managers.state_manager.current_state = super::states::StateType::GameInitError;
+        }
+    }
+
+    ui_components.menu.selected_index = 0;
+}
+
+// Start the game in the chosen town, the world must already be loaded
+fn start_game(
+    managers: &mut crate::ui::display::Managers,
+    ui_components: &mut crate::ui::display::UIComponents,
+    town_name: &str,
+) -> Result<(), Box<dyn std::error::Error>> {
+    if managers.world_manager.town_by_name(town_name).is_none() {
+        return Err(format!("No town named {} in this world", town_name).into());
+    }
+
+    managers.world_manager.player = Some(crate::entities::player::Player::new(
+        managers.world_manager.new_player_id(),
+        ui_components.popup.input.clone(),
+        town_name.to_string(),
+        managers.config.starting_gold,
+        managers.world_manager.character_creation.character(),
+    ));
+
+    // Each new character gets their own save slot
+    let slot = managers
+        .save_manager
+        .new_slot_name(&ui_components.popup.input);
+    managers.save_manager.begin_session(slot, 0);
+
+    managers
+        .time_manager
+        .start(crate::world::time::GameTime::new());
+    managers.weather_manager.start(
+        crate::world::weather::GameWeather::new(),
+        managers.world_manager.dice.fork(),
+    );
+
+    Ok(())
+}
+
+// Pay the fare and set off on the current leg of the journey
+// Returns a notice for the player if they can't
+fn begin_leg(managers: &mut crate::ui::display::Managers) -> Result<(), String> {
+    let world_manager = &mut managers.world_manager;
+
+    let (Some(journey), Some(player)) = (
+        world_manager.journey.as_mut(),
+        world_manager.player.as_mut(),
+    ) else {
+        return Err("You aren't going anywhere.".into());
+    };
+
+    let Some(leg) = journey.current_leg() else {
+        return Err("You aren't going anywhere.".into());
+    };
+
+    let fare = player.effects.adjust_fare(leg.cost);
+
+    if player.gold < fare {
+        return Err(format!(
+            "You can't afford the {} gold fare on to {}.",
+            fare, leg.to
+        ));
+    }
+
+    player.gold -= fare;
+
+    let travel_time = player.effects.adjust_travel_time(leg.travel_time());
+    journey.start_leg(travel_time, &mut world_manager.dice);
+
+    Ok(())
 }
 
-// Load game from save
+// Carry on with the journey, or the encounter that interrupted it
+pub fn resume_journey(
+    managers: &mut crate::ui::display::Managers,
+    ui_components: &mut crate::ui::display::UIComponents,
+) {
+    let Some(journey) = managers.world_manager.journey.as_mut() else {
+        managers.state_manager.current_state = super::states::StateType::Game;
+        return;
+    };
+
+    // Start counting time from now so time spent away from the road isn't travelled
+    journey.last_update = None;
+
+    managers.state_manager.current_state = if journey.encounter.is_some() {
+        super::states::StateType::JourneyEncounter
+    } else {
+        super::states::StateType::Journey
+    };
+    ui_components.menu.selected_index = 0;
+}
+
+// Move the journey along by however much real time has passed, called every frame
+pub fn update_journey(
+    managers: &mut crate::ui::display::Managers,
+    ui_components: &mut crate::ui::display::UIComponents,
+) {
+    if !matches!(
+        managers.state_manager.current_state,
+        super::states::StateType::Journey
+    ) {
+        return;
+    }
+
+    let Some(journey) = managers.world_manager.journey.as_mut() else {
+        managers.state_manager.current_state = super::states::StateType::Game;
+        return;
+    };
+
+    let now = std::time::Instant::now();
+    let last_update = *journey.last_update.get_or_insert(now);
+
+    let ticks = now.duration_since(last_update).as_millis() as u64
+        * crate::world::journey::JOURNEY_TICKS_PER_SEC as u64
+        / 1000;
+
+    if ticks == 0 {
+        return;
+    }
+
+    journey.last_update = Some(now);
+
+    let (travelled, progress) = journey.travel(ticks as u32);
+    managers.time_manager.advance(travelled);
+
+    match progress {
+        crate::world::journey::Progress::Travelling => {}
+        crate::world::journey::Progress::Encounter => {
+            managers.state_manager.current_state = super::states::StateType::JourneyEncounter;
+            ui_components.menu.selected_index = 0;
+        }
+        crate::world::journey::Progress::LegComplete => finish_leg(managers, ui_components),
+    }
+}
+
+// Arrive at the end of a leg, then either set off on the next one or end the journey
+fn finish_leg(
+    managers: &mut crate::ui::display::Managers,
+    ui_components: &mut crate::ui::display::UIComponents,
+) {
+    let world_manager = &mut managers.world_manager;
+
+    let Some(journey) = world_manager.journey.as_mut() else {
+        return;
+    };
+
+    // Arrive at the edge of the next town
+    if let (Some(leg), Some(player)) = (journey.current_leg(), world_manager.player.as_mut()) {
+        player.town_name = leg.to.clone();
+        player.position = (0, 0);
+    }
+
+    journey.leg += 1;
+
+    if journey.stop_early || journey.leg >= journey.legs.len() {
+        end_journey(managers, ui_components, None);
+        return;
+    }
+
+    if let Err(notice) = begin_leg(managers) {
+        end_journey(managers, ui_components, Some(notice));
+    }
+}
+
+// End the journey in whichever town the player has reached
+fn end_journey(
+    managers: &mut crate::ui::display::Managers,
+    ui_components: &mut crate::ui::display::UIComponents,
+    notice: Option<String>,
+) {
+    let journey = managers.world_manager.journey.take();
+
+    let mut notices: Vec<String> = notice.into_iter().collect();
+
+    if let (Some(journey), Some(player)) = (journey, managers.world_manager.player.as_ref()) {
+        if player.town_name != journey.destination {
+            notices.push(format!(
+                "You stop in {}, short of {}.",
+                player.town_name, journey.destination
+            ));
+        }
+    }
+
+    // Travelling through bad weather can leave the player chilled
+    let bad_weather = managers
+        .weather_manager
+        .weather_arc_rwlock
+        .as_ref()
+        .and_then(|weather| weather.read().ok())
+        .is_some_and(|weather| {
+            matches!(
+                weather.weather_type,
+                crate::world::weather::WeatherType::Stormy
+                    | crate::world::weather::WeatherType::Snowing
+            )
+        });
+
+    if let (true, Some(arrival_time), Some(player)) = (
+        bad_weather,
+        managers.time_manager.current(),
+        managers.world_manager.player.as_mut(),
+    ) {
+        // Blessed travellers find shelter more easily, the already chilled less so
+        let mode = if player
+            .effects
+            .has(&crate::entities::effects::EffectKind::Wayfarer)
+        {
+            crate::core::dice::RollMode::Advantage
+        } else if player
+            .effects
+            .has(&crate::entities::effects::EffectKind::Chilled)
+        {
+            crate::core::dice::RollMode::Disadvantage
+        } else {
+            crate::core::dice::RollMode::Normal
+        };
+
+        let check = managers.world_manager.dice.skill_check(
+            &player.character.attributes,
+            crate::entities::character::Attribute::Strength,
+            CHILL_DC,
+            mode,
+        );
+
+        if check.success {
+            notices.push(format!("You shrug off the cold. {}", check.describe()));
+        } else {
+            player.effects.apply(
+                crate::entities::effects::EffectKind::Chilled,
+                crate::world::time::TICKS_PER_DAY,
+                &arrival_time,
+            );
+
+            notices.push(format!(
+                "The cold gets into your bones. {}",
+                check.describe()
+            ));
+        }
+    }
+
+    if managers.config.autosave_on_travel {
+        autosave(managers);
+    }
+
+    ui_components.viewport.notice = if notices.is_empty() {
+        None
+    } else {
+        Some(notices.join(" "))
+    };
+
+    managers.state_manager.current_state = super::states::StateType::Game;
+    ui_components.menu.selected_index = 0;
+}
+
+// Move on from an attempt to load a save, asking the player what to do if it needs their say
+fn finish_load(
+    managers: &mut crate::ui::display::Managers,
+    ui_components: &mut crate::ui::display::UIComponents,
+    slot: &str,
+    loaded: Result<(), Box<dyn std::error::Error>>,
+) {
+    let Err(e) = loaded else {
+        // Pick up a journey where it was saved
+        if managers.world_manager.journey.is_some() {
+            resume_journey(managers, ui_components);
+        } else {
+            managers.state_manager.current_state = super::states::StateType::Game;
+        }
+
+        return;
+    };
+
+    log::error!("Failed to load save {}: {}", slot, e);
+
+    if e.downcast_ref::<crate::core::save::ChecksumMismatch>()
+        .is_some()
+    {
+        managers.state_manager.current_state = super::states::StateType::LoadChecksumMismatch;
+    } else if let Some(mismatch) = e.downcast_ref::<crate::core::save::WorldMismatch>() {
+        managers.save_manager.world_mismatch = Some(mismatch.clone());
+        managers.state_manager.current_state = super::states::StateType::LoadWorldMismatch;
+    } else {
+        // Keep the reason to show the player
+        ui_components.viewport.notice = Some(e.to_string());
+
+        managers.state_manager.current_state = super::states::StateType::GameLoadError;
+    }
+}
+
+// Load game from a save slot
 fn load_game(
     managers: &mut crate::ui::display::Managers,
+    slot: &str,
+    verify: bool,
+    world_dir: Option<std::path::PathBuf>,
 ) -> Result<(), Box<dyn std::error::Error>> {
     managers.world_manager.clear();
+    managers.dialogue_manager.clear();
+    managers.narrator_manager.clear();
+
+    let save_data = managers.save_manager.load(slot, verify)?;
+
+    // Check the save was made against the world about to be loaded, unless one was chosen
+    match world_dir {
+        Some(world_dir) => managers.world_manager.world_dir = world_dir,
+        None => {
+            if let Some(saved) = save_data.world.as_ref() {
+                let current = crate::world::manager::WorldIdentity::from_dir(
+                    &managers.world_manager.world_dir,
+                )?;
+
+                if current.hash != saved.hash {
+                    let matching_available = crate::world::manager::WorldIdentity::from_dir(
+                        std::path::Path::new(&saved.path),
+                    )
+                    .is_ok_and(|world| world.hash == saved.hash);
+
+                    // Play the save's own world if it's still there unchanged, unless a world was given on the command line
+                    if matching_available && !managers.world_manager.world_chosen {
+                        managers.world_manager.world_dir = std::path::PathBuf::from(&saved.path);
+                    } else {
+                        return Err(Box::new(crate::core::save::WorldMismatch {
+                            saved: saved.clone(),
+                            current,
+                            matching_available,
+                            verify,
+                        }));
+                    }
+                }
+            }
+        }
+    }
 
     managers.world_manager.load_world()?;
 
-    let save_data = managers.save_manager.load()?;
+    let play_time_secs = save_data
+        .metadata
+        .as_ref()
+        .map_or(0, |metadata| metadata.play_time_secs);
+    managers
+        .save_manager
+        .begin_session(slot.to_string(), play_time_secs);
+
+    // Reloading the same save always rolls the same way from then on
+    managers.world_manager.dice =
+        crate::core::dice::Dice::new(save_data.world_seed ^ play_time_secs);
 
     if let Some(player) = save_data.player {
         managers.world_manager.player = Some(player);
@@ -306,10 +1644,220 @@ fn load_game(
     }
 
     if let Some(initial_game_weather) = save_data.weather {
-        managers.weather_manager.start(initial_game_weather);
+        managers
+            .weather_manager
+            .start(initial_game_weather, managers.world_manager.dice.fork());
     } else {
         log::error!("Failed to load GameWeather: No GameWeather found.");
     }
 
+    // A save loaded into a different world may be in a town that doesn't exist there
+    if managers.world_manager.current_town().is_none() {
+        let fallback_town = managers
+            .world_manager
+            .travel_destinations()
+            .into_iter()
+            .next();
+
+        if let (Some(player), Some(fallback_town)) =
+            (managers.world_manager.player.as_mut(), fallback_town)
+        {
+            log::error!(
+                "Town {} not found in this world, moving player to {}",
+                player.town_name,
+                fallback_town
+            );
+
+            player.town_name = fallback_town;
+        }
+    }
+
+    managers.world_manager.world_seed = save_data.world_seed;
+    managers.world_manager.container_contents = save_data.containers;
+    managers.world_manager.shop_stock = save_data.shops;
+    managers.world_manager.journey = save_data.journey;
+
+    managers.dialogue_manager.memories = save_data.dialogue_memory;
+
     Ok(())
 }
+
+// Pay for a bed and sleep until dawn
+// Returns a notice for the player, as an error if they couldn't rest
+fn rest(managers: &mut crate::ui::display::Managers) -> Result<String, String> {
+    let Some(player) = managers.world_manager.player.as_mut() else {
+        return Err("There's nobody to rest.".to_string());
+    };
+
+    if player.gold < crate::world::tavern::REST_COST {
+        return Err(format!(
+            "A bed costs {} gold and you can't afford it.",
+            crate::world::tavern::REST_COST
+        ));
+    }
+
+    let Some(time) = managers.time_manager.current() else {
+        return Err("You can't seem to get to sleep.".to_string());
+    };
+
+    player.gold -= crate::world::tavern::REST_COST;
+    managers.time_manager.advance(time.ticks_until_next_dawn());
+
+    Ok(format!(
+        "You pay {} gold for a bed and wake rested at dawn.",
+        crate::world::tavern::REST_COST
+    ))
+}
+
+// Pay for one of the temple's services
+// Returns a notice for the player
+fn temple_service(managers: &mut crate::ui::display::Managers, index: usize) -> String {
+    let Some(service) = crate::world::temple::SERVICES.get(index) else {
+        return "The priest doesn't offer that.".to_string();
+    };
+
+    let (Some(player), Some(time)) = (
+        managers.world_manager.player.as_mut(),
+        managers.time_manager.current(),
+    ) else {
+        return "The priest doesn't seem to notice you.".to_string();
+    };
+
+    if player.gold < service.cost {
+        return format!(
+            "The {} costs {} gold and you can't afford it.",
+            service.name, service.cost
+        );
+    }
+
+    match &service.kind {
+        crate::world::temple::ServiceKind::Bless(kind, duration_ticks) => {
+            player.effects.apply(kind.clone(), *duration_ticks, &time);
+            player.gold -= service.cost;
+
+            format!("You receive the {}.", service.name)
+        }
+        crate::world::temple::ServiceKind::Heal => {
+            if player.effects.remove_harmful() == 0 {
+                return "The priest looks you over and finds nothing to heal.".to_string();
+            }
+
+            player.gold -= service.cost;
+
+            "The priest lays hands on you and you feel yourself again.".to_string()
+        }
+    }
+}
+
+// Buy one of the item at index from the current shop
+// Returns a notice for the player, or None if nothing was selected
+fn buy_item(managers: &mut crate::ui::display::Managers, index: usize) -> Option<String> {
+    let town_id = managers.world_manager.current_town()?.id();
+    let building_id = managers.world_manager.current_building()?.id();
+
+    let world_manager = &mut managers.world_manager;
+    let stock = world_manager.shop_stock.get_mut(&building_id)?;
+    let player = world_manager.player.as_mut()?;
+
+    let stack = stock.stacks.get(index)?;
+    let reputation = player.reputation_in(town_id);
+    let price =
+        crate::world::shop::buy_price(&stack.item, town_id, player.trading_reputation(town_id));
+
+    if player.gold < price {
+        return Some(format!(
+            "You can't afford the {} ({} gold).",
+            stack.item.name, price
+        ));
+    }
+
+    if let Err(e) = player.inventory.add(stack.item.clone(), 1) {
+        return Some(e.to_string());
+    }
+
+    player.gold -= price;
+    *player.reputation.entry(town_id).or_insert(0) +=
+        crate::world::shop::reputation_gain(reputation);
+
+    let item_name = stack.item.name.clone();
+
+    stock.stacks[index].quantity -= 1;
+    if stock.stacks[index].quantity == 0 {
+        stock.stacks.remove(index);
+    }
+
+    Some(format!("You buy the {} for {} gold.", item_name, price))
+}
+
+// Sell one of the item at index in the player's inventory to the current shop
+// Returns a notice for the player, or None if nothing was selected
+fn sell_item(managers: &mut crate::ui::display::Managers, index: usize) -> Option<String> {
+    let town_id = managers.world_manager.current_town()?.id();
+    let building_id = managers.world_manager.current_building()?.id();
+
+    let world_manager = &mut managers.world_manager;
+    let stock = world_manager.shop_stock.get_mut(&building_id)?;
+    let player = world_manager.player.as_mut()?;
+
+    let reputation = player.trading_reputation(town_id);
+    let sold = player.inventory.remove(index, 1)?;
+    let price = crate::world::shop::sell_price(&sold.item, town_id, reputation);
+
+    player.gold += price;
+
+    let notice = format!("You sell the {} for {} gold.", sold.item.name, price);
+    stock.add(sold.item, sold.quantity);
+
+    Some(notice)
+}
+
+// Send the player's message to the NPC they are talking to
+fn send_message(
+    managers: &mut crate::ui::display::Managers,
+    message: String,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let context = dialogue_context(managers).ok_or("Failed to find NPC for conversation.")?;
+
+    managers.dialogue_manager.send(context, message)
+}
+
+// Build the context for the NPC currently being talked to
+fn dialogue_context(
+    managers: &crate::ui::display::Managers,
+) -> Option<crate::ai::dialogue::DialogueContext> {
+    let npc_id = managers.dialogue_manager.npc_id?;
+    let town = managers.world_manager.current_town()?;
+    let building = managers.world_manager.current_building()?;
+    let npc = managers
+        .world_manager
+        .current_room()?
+        .npcs()
+        .iter()
+        .find(|npc| npc.id() == npc_id)?;
+
+    let phase = managers
+        .time_manager
+        .time_arc_rwlock
+        .as_ref()
+        .and_then(|game_time| game_time.read().ok().map(|t| t.phase.clone()))
+        .unwrap_or(crate::world::time::Phase::Day);
+
+    let weather = managers
+        .weather_manager
+        .weather_arc_rwlock
+        .as_ref()
+        .and_then(|game_weather| game_weather.read().ok().map(|w| w.weather_type.clone()))
+        .unwrap_or(crate::world::weather::WeatherType::Sunny);
+
+    Some(crate::ai::dialogue::DialogueContext {
+        npc_name: npc.name().to_string(),
+        npc_race: npc.race().clone(),
+        npc_sex: npc.sex().clone(),
+        building_name: building.name().to_string(),
+        building_type: building.building_type().clone(),
+        town_name: town.name.clone(),
+        phase,
+        weather,
+        new_conversation: false,
+    })
+}
@@ -0,0 +1,11 @@
+// FNV-1a hash of some bytes as hex, quick and stable between runs but not cryptographic
+pub fn fnv1a(bytes: &[u8]) -> String {
+    let mut hash: u64 = 0xCBF2_9CE4_8422_2325;
+
+    for byte in bytes {
+        hash ^= *byte as u64;
+        hash = hash.wrapping_mul(0x0100_0000_01B3);
+    }
+
+    format!("{:016x}", hash)
+}
@@ -0,0 +1,517 @@
+use serde_json::{Map, Value};
+
+// Const for the save format version this build writes
+// Bumped whenever saves gain or change fields, so an older build refuses a save it would lose data from
+// 0: player, time and weather only, from before saves were versioned
+// 1: player gold, inventory, reputation and status effects, world seed, containers, shops, NPC memories and slot metadata
+// 2: the world the save was made in
+// 3: the player's character, race, sex, background and attributes
+// 4: the journey in progress, if the player was on the road
+// 5: the player's position on the town map
+pub const CURRENT_VERSION: u32 = 5;
+
+// Consts for the migrations that reshape a save, (version migrated from, migration)
+// Versions with no migration only added optional fields, serde defaults fill them in when the save is read
+type Migration = fn(&mut Map<String, Value>) -> Result<(), LoadError>;
+const MIGRATIONS: [(u32, Migration); 1] = [(0, migrate_v0_to_v1)];
+
+// Upgrade raw save JSON to the current version, returns the version it was saved with
+pub fn migrate(save: &mut Value) -> Result<u32, LoadError> {
+    let save = save
+        .as_object_mut()
+        .ok_or_else(|| LoadError::new(0, None, "save is not a JSON object"))?;
+
+    // Saves from before versioning have no version field
+    let original_version = match save.get("version") {
+        None => 0,
+        Some(version) => version
+            .as_u64()
+            .and_then(|version| u32::try_from(version).ok())
+            .ok_or_else(|| LoadError::new(0, Some("version"), "expected a whole number"))?,
+    };
+
+    if original_version > CURRENT_VERSION {
+        return Err(LoadError::new(
+            original_version,
+            Some("version"),
+            &format!(
+                "newer than this game supports (version {})",
+                CURRENT_VERSION
+            ),
+        ));
+    }
+
+    apply_migrations(save, original_version, CURRENT_VERSION, &MIGRATIONS)?;
+
+    Ok(original_version)
+}
+
+// Step a save from one version to another, running each version's migration once and in order
+fn apply_migrations(
+    save: &mut Map<String, Value>,
+    from_version: u32,
+    to_version: u32,
+    migrations: &[(u32, Migration)],
+) -> Result<(), LoadError> {
+    for version in from_version..to_version {
+        if let Some((_, migration)) = migrations.iter().find(|(from, _)| *from == version) {
+            migration(save)?;
+        }
+
+        log::info!("Migrated save from version {} to {}", version, version + 1);
+        save.insert("version".into(), Value::from(version + 1));
+    }
+
+    Ok(())
+}
+
+// Version 0 to 1: fill in the player's gold and reputation and the world state, none of which existed before versioning
+// Pre-version players always had the starting gold, so that's written in rather than left to whatever the default becomes
+fn migrate_v0_to_v1(save: &mut Map<String, Value>) -> Result<(), LoadError> {
+    if let Some(player) = save.get_mut("player").filter(|player| !player.is_null()) {
+        let player = player
+            .as_object_mut()
+            .ok_or_else(|| LoadError::new(0, Some("player"), "expected an object"))?;
+
+        player
+            .entry("gold")
+            .or_insert_with(|| Value::from(crate::entities::player::STARTING_GOLD));
+        player
+            .entry("reputation")
+            .or_insert_with(|| Value::Object(Map::new()));
+    }
+
+    save.entry("world_seed").or_insert_with(|| Value::from(0));
+    for field in ["containers", "shops", "dialogue_memory"] {
+        save.entry(field)
+            .or_insert_with(|| Value::Object(Map::new()));
+    }
+
+    Ok(())
+}
+
+// Struct for a save that couldn't be loaded, naming the version and field at fault
+#[derive(Debug)]
+pub struct LoadError {
+    version: u32,
+    field: Option<String>,
+    message: String,
+}
+
+// Functions for Load Error
+impl LoadError {
+    // Create a new Load Error
+    pub fn new(version: u32, field: Option<&str>, message: &str) -> Self {
+        Self {
+            version,
+            field: field.map(|field| field.to_string()),
+            message: message.to_string(),
+        }
+    }
+}
+
+impl std::fmt::Display for LoadError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.field {
+            Some(field) => write!(
+                f,
+                "Save version {}, field `{}`: {}",
+                self.version, field, self.message
+            ),
+            None => write!(f, "Save version {}: {}", self.version, self.message),
+        }
+    }
+}
+
+impl std::error::Error for LoadError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::save::SaveData;
+
+    // A save from before versioning, only the player, time and weather
+    const SAVE_V0: &str = r#"{
+        "player": { "id": 7, "name": "Ada", "town_name": "Ash Burhwich" },
+        "time": { "tick": 120, "day": 3, "phase": "Day" },
+        "weather": { "weather_type": "Raining" }
+    }"#;
+
+    // A version 1 save, with gold, inventory, reputation, effects and world state
+    const SAVE_V1: &str = r#"{
+        "version": 1,
+        "player": {
+            "id": 7,
+            "name": "Ada",
+            "town_name": "Ash Burhwich",
+            "gold": 230,
+            "inventory": {
+                "stacks": [
+                    {
+                        "item": {
+                            "id": 1,
+                            "name": "Bread",
+                            "kind": "Food",
+                            "weight": 0.5,
+                            "value": 2,
+                            "max_stack": 20
+                        },
+                        "quantity": 4
+                    }
+                ],
+                "max_slots": 20,
+                "max_weight": 50.0
+            },
+            "reputation": { "4": 2 },
+            "effects": {
+                "effects": [{ "kind": "Fortune", "expires_day": 5, "expires_tick": 0 }]
+            }
+        },
+        "time": { "tick": 450, "day": 4, "phase": "Dusk" },
+        "weather": { "weather_type": "Snowing" },
+        "world_seed": 12345,
+        "containers": { "9": [] },
+        "shops": { "3": { "restock_cycle": 2, "stacks": [] } },
+        "dialogue_memory": {
+            "11": { "summary": "Met the traveller.", "transcript": [{ "speaker": "Player", "text": "Hi" }] }
+        }
+    }"#;
+
+    // A version 2 save, the version 1 save plus the world it was made in
+    fn save_v2() -> String {
+        let mut save: Value = serde_json::from_str(SAVE_V1).unwrap();
+        save["version"] = Value::from(2);
+        save["world"] = serde_json::json!({ "path": "worlds/isles", "hash": "9f2c1e" });
+
+        save.to_string()
+    }
+
+    // A version 3 save, the version 2 save plus the player's character
+    fn save_v3() -> String {
+        let mut save: Value = serde_json::from_str(&save_v2()).unwrap();
+        save["version"] = Value::from(3);
+        save["player"]["character"] = serde_json::json!({
+            "race": "Elf",
+            "sex": "Female",
+            "background": "Scholar",
+            "attributes": { "strength": 4, "agility": 6, "wit": 9, "charm": 5 }
+        });
+
+        save.to_string()
+    }
+
+    // A version 4 save, the version 3 save made part way along a journey
+    fn save_v4() -> String {
+        let mut save: Value = serde_json::from_str(&save_v3()).unwrap();
+        save["version"] = Value::from(4);
+        save["journey"] = serde_json::json!({
+            "destination": "Kelmarsh",
+            "legs": [
+                { "from": "Ash Burhwich", "to": "Dunmere", "distance": 12, "cost": 60 },
+                { "from": "Dunmere", "to": "Kelmarsh", "distance": 8, "cost": 40 }
+            ],
+            "leg": 1,
+            "leg_progress": 3,
+            "leg_length": 8,
+            "pending_encounter": [5, { "Bandits": { "toll": 15 } }],
+            "encounter": null,
+            "stop_early": false
+        });
+
+        save.to_string()
+    }
+
+    // A version 5 save, the version 4 save with the player's spot on the town map
+    fn save_v5() -> String {
+        let mut save: Value = serde_json::from_str(&save_v4()).unwrap();
+        save["version"] = Value::from(5);
+        save["player"]["position"] = serde_json::json!([3, 2]);
+
+        save.to_string()
+    }
+
+    fn load(fixture: &str) -> Result<(u32, SaveData), LoadError> {
+        let mut save_json: Value = serde_json::from_str(fixture).unwrap();
+        let version = migrate(&mut save_json)?;
+
+        assert_eq!(save_json["version"], Value::from(CURRENT_VERSION));
+
+        Ok((version, SaveData::from_json(save_json, version)?))
+    }
+
+    #[test]
+    fn loads_a_version_0_save() {
+        let (version, save) = load(SAVE_V0).unwrap();
+        assert_eq!(version, 0);
+
+        let player = save.player.unwrap();
+        assert_eq!(player.id, 7);
+        assert_eq!(player.name, "Ada");
+        assert_eq!(player.town_name, "Ash Burhwich");
+        assert_eq!(player.gold, crate::entities::player::STARTING_GOLD);
+        assert!(player.inventory.stacks.is_empty());
+        assert!(player.reputation.is_empty());
+        assert!(player.effects.effects.is_empty());
+
+        let time = save.time.unwrap();
+        assert_eq!((time.day, time.tick), (3, 120));
+        assert_eq!(time.phase, crate::world::time::Phase::Day);
+        assert_eq!(
+            save.weather.unwrap().weather_type,
+            crate::world::weather::WeatherType::Raining
+        );
+
+        assert_eq!(save.world_seed, 0);
+        assert!(save.containers.is_empty());
+        assert!(save.shops.is_empty());
+        assert!(save.dialogue_memory.is_empty());
+    }
+
+    #[test]
+    fn loads_a_version_1_save() {
+        let (version, save) = load(SAVE_V1).unwrap();
+        assert_eq!(version, 1);
+
+        let player = save.player.unwrap();
+        assert_eq!(player.gold, 230);
+        assert_eq!(player.inventory.stacks.len(), 1);
+        assert_eq!(player.inventory.stacks[0].item.name, "Bread");
+        assert_eq!(player.inventory.stacks[0].quantity, 4);
+        assert_eq!(player.reputation_in(4), 2);
+        assert!(player
+            .effects
+            .has(&crate::entities::effects::EffectKind::Fortune));
+
+        assert_eq!(save.time.unwrap().day, 4);
+        assert_eq!(
+            save.weather.unwrap().weather_type,
+            crate::world::weather::WeatherType::Snowing
+        );
+        assert_eq!(save.world_seed, 12345);
+        assert!(save.containers[&9].is_empty());
+        assert_eq!(save.shops[&3].restock_cycle, 2);
+        assert_eq!(save.dialogue_memory[&11].summary, "Met the traveller.");
+        assert_eq!(save.dialogue_memory[&11].transcript.len(), 1);
+        assert!(save.world.is_none());
+    }
+
+    #[test]
+    fn loads_a_version_2_save() {
+        let (version, save) = load(&save_v2()).unwrap();
+        assert_eq!(version, 2);
+
+        let world = save.world.unwrap();
+        assert_eq!(world.path, "worlds/isles");
+        assert_eq!(world.hash, "9f2c1e");
+
+        let character = save.player.unwrap().character;
+        assert_eq!(character.race, crate::world::manager::NpcRace::Human);
+        assert_eq!(
+            character
+                .attributes
+                .get(crate::entities::character::Attribute::Wit),
+            crate::entities::character::Attributes::default().wit
+        );
+    }
+
+    #[test]
+    fn loads_a_version_3_save() {
+        let (version, save) = load(&save_v3()).unwrap();
+        assert_eq!(version, 3);
+
+        let player = save.player.unwrap();
+        assert_eq!(player.gold, 230);
+        assert_eq!(player.character.race, crate::world::manager::NpcRace::Elf);
+        assert_eq!(player.character.sex, crate::world::manager::NpcSex::Female);
+        assert_eq!(
+            player.character.background,
+            crate::entities::character::Background::Scholar
+        );
+        assert_eq!(player.character.attributes.wit, 9);
+        assert!(save.world.is_some());
+        assert!(save.journey.is_none());
+    }
+
+    #[test]
+    fn loads_a_version_4_save() {
+        let (version, save) = load(&save_v4()).unwrap();
+        assert_eq!(version, 4);
+
+        let journey = save.journey.unwrap();
+        assert_eq!(journey.destination, "Kelmarsh");
+        assert_eq!(journey.legs.len(), 2);
+        assert_eq!(journey.legs[1].from, "Dunmere");
+        assert_eq!(journey.legs[1].cost, 40);
+        assert_eq!(
+            (journey.leg, journey.leg_progress, journey.leg_length),
+            (1, 3, 8)
+        );
+        assert!(matches!(
+            journey.pending_encounter,
+            Some((5, crate::world::journey::Encounter::Bandits { toll: 15 }))
+        ));
+        assert!(journey.encounter.is_none());
+        assert!(journey.last_update.is_none());
+
+        let player = save.player.unwrap();
+        assert_eq!(player.character.attributes.wit, 9);
+        assert_eq!(player.position, (0, 0));
+    }
+
+    #[test]
+    fn loads_a_version_5_save() {
+        let (version, save) = load(&save_v5()).unwrap();
+        assert_eq!(version, 5);
+
+        let player = save.player.unwrap();
+        assert_eq!(player.position, (3, 2));
+        assert_eq!(player.gold, 230);
+        assert_eq!(save.journey.unwrap().destination, "Kelmarsh");
+    }
+
+    #[test]
+    fn rejects_a_save_from_a_newer_version() {
+        let mut save_json = serde_json::json!({ "version": CURRENT_VERSION + 1 });
+        let error = migrate(&mut save_json).unwrap_err();
+
+        assert_eq!(error.version, CURRENT_VERSION + 1);
+        assert_eq!(error.field.as_deref(), Some("version"));
+        assert_eq!(
+            error.to_string(),
+            format!(
+                "Save version {}, field `version`: newer than this game supports (version {})",
+                CURRENT_VERSION + 1,
+                CURRENT_VERSION
+            )
+        );
+    }
+
+    #[test]
+    fn rejects_a_version_that_isnt_a_number() {
+        let mut save_json = serde_json::json!({ "version": "one" });
+        let error = migrate(&mut save_json).unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "Save version 0, field `version`: expected a whole number"
+        );
+    }
+
+    #[test]
+    fn names_the_version_and_field_of_a_malformed_field() {
+        let fixture = SAVE_V1.replace("\"world_seed\": 12345", "\"world_seed\": \"lots\"");
+        let error = load(&fixture).unwrap_err();
+
+        assert_eq!(error.version, 1);
+        assert_eq!(error.field.as_deref(), Some("world_seed"));
+        assert_eq!(
+            error.to_string(),
+            "Save version 1, field `world_seed`: invalid type: string \"lots\", expected u64"
+        );
+    }
+
+    #[test]
+    fn rejects_attributes_character_creation_couldnt_make() {
+        for (attribute, value) in [("wit", 14), ("strength", 2)] {
+            let mut save_json: Value = serde_json::from_str(&save_v3()).unwrap();
+            save_json["player"]["character"]["attributes"][attribute] = Value::from(value);
+
+            let error = load(&save_json.to_string()).unwrap_err();
+
+            assert_eq!(error.version, 3);
+            assert_eq!(error.field.as_deref(), Some("player"));
+            assert!(error.message.ends_with(&format!(
+                "is {}, attributes go from {} to {}",
+                value,
+                crate::entities::character::ATTRIBUTE_MIN,
+                crate::entities::character::ATTRIBUTE_MAX + 1
+            )));
+        }
+    }
+
+    #[test]
+    fn fills_in_a_version_0_save_explicitly() {
+        let mut save_json: Value = serde_json::from_str(SAVE_V0).unwrap();
+        migrate(&mut save_json).unwrap();
+
+        assert_eq!(
+            save_json["player"]["gold"],
+            Value::from(crate::entities::player::STARTING_GOLD)
+        );
+        assert_eq!(save_json["player"]["reputation"], serde_json::json!({}));
+        assert_eq!(save_json["world_seed"], Value::from(0));
+        assert_eq!(save_json["shops"], serde_json::json!({}));
+    }
+
+    #[test]
+    fn names_the_version_and_field_a_migration_failed_on() {
+        let mut save_json = serde_json::json!({ "player": 5 });
+        let error = migrate(&mut save_json).unwrap_err();
+
+        assert_eq!(error.version, 0);
+        assert_eq!(error.field.as_deref(), Some("player"));
+        assert_eq!(
+            error.to_string(),
+            "Save version 0, field `player`: expected an object"
+        );
+    }
+
+    // Migrations for testing the chain, each records the version it ran from
+    fn record(save: &mut Map<String, Value>) -> Result<(), LoadError> {
+        let version = save["version"].clone();
+        save.entry("steps")
+            .or_insert_with(|| Value::Array(Vec::new()))
+            .as_array_mut()
+            .unwrap()
+            .push(version);
+
+        Ok(())
+    }
+
+    fn fail(save: &mut Map<String, Value>) -> Result<(), LoadError> {
+        let version = save["version"].as_u64().unwrap() as u32;
+
+        Err(LoadError::new(
+            version,
+            Some("town"),
+            "renamed town not found",
+        ))
+    }
+
+    #[test]
+    fn runs_each_migration_once_in_version_order() {
+        let migrations: [(u32, Migration); 3] = [(3, record), (1, record), (4, record)];
+
+        let mut save = Map::new();
+        save.insert("version".into(), Value::from(1));
+        apply_migrations(&mut save, 1, 4, &migrations).unwrap();
+
+        // Version 2 has no migration and version 4 is already current
+        assert_eq!(save["steps"], serde_json::json!([1, 3]));
+        assert_eq!(save["version"], Value::from(4));
+    }
+
+    #[test]
+    fn stops_at_the_migration_that_failed() {
+        let migrations: [(u32, Migration); 3] = [(0, record), (1, fail), (2, record)];
+
+        let mut save = Map::new();
+        save.insert("version".into(), Value::from(0));
+        let error = apply_migrations(&mut save, 0, 3, &migrations).unwrap_err();
+
+        assert_eq!(error.version, 1);
+        assert_eq!(error.field.as_deref(), Some("town"));
+        assert_eq!(save["steps"], serde_json::json!([0]));
+        assert_eq!(save["version"], Value::from(1));
+    }
+
+    #[test]
+    fn rejects_a_save_that_isnt_an_object() {
+        let mut save_json = serde_json::json!([1, 2, 3]);
+
+        assert_eq!(
+            migrate(&mut save_json).unwrap_err().to_string(),
+            "Save version 0: save is not a JSON object"
+        );
+    }
+}
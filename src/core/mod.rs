@@ -1,3 +1,7 @@
+pub mod config;
+pub mod dice;
 pub mod events;
+pub mod hash;
+pub mod migrations;
 pub mod save;
 pub mod states;
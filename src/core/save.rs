@@ -1,28 +1,243 @@
 use serde::{Deserialize, Serialize};
 use serde_json;
+use std::collections::HashMap;
 use std::fs;
 use std::path::PathBuf;
+use std::time::{Instant, SystemTime, UNIX_EPOCH};
+
+// Const for the slot used when no better name can be made
+const DEFAULT_SLOT: &str = "save";
+
+// Const for the directory saves are kept in
+const SAVES_DIR: &str = "saves";
 
 // Struct for Save Game Manager
 pub struct SaveGameManager {
     save_data: SaveData,
+    pub current_slot: Option<String>,
+    pub slots: Vec<SaveSlot>,
+    pub selected_slot: Option<usize>,
+    pub world_mismatch: Option<WorldMismatch>,
+    play_time_secs: u64,
+    session_started: Instant,
+    last_saved: Instant,
+    backups: usize,
+    format: super::config::SaveFormat,
+    saves_dir: PathBuf,
 }
 
 // Functions for Save Game Manager
 impl SaveGameManager {
-    // Create a new Save Game Manager
-    pub fn new() -> Self {
+    // Create a new Save Game Manager, keeping as many backups per slot as the config asks for
+    pub fn new(config: &super::config::Config) -> Self {
         Self {
             save_data: SaveData::new(),
+            current_slot: None,
+            slots: Vec::new(),
+            selected_slot: None,
+            world_mismatch: None,
+            play_time_secs: 0,
+            session_started: Instant::now(),
+            last_saved: Instant::now(),
+            backups: config.save_backups,
+            format: config.save_format.clone(),
+            saves_dir: PathBuf::from(SAVES_DIR),
+        }
+    }
+
+    // Start tracking a game session that saves to the given slot
+    pub fn begin_session(&mut self, slot: String, play_time_secs: u64) {
+        self.current_slot = Some(slot);
+        self.play_time_secs = play_time_secs;
+        self.session_started = Instant::now();
+        self.last_saved = Instant::now();
+    }
+
+    // Stop tracking the game session, nothing is autosaved until the next one begins
+    pub fn end_session(&mut self) {
+        self.current_slot = None;
+    }
+
+    // Check whether a game is in progress and it's been long enough since the last save
+    // An interval of 0 turns interval autosaves off
+    pub fn autosave_due(&self, interval_secs: u64) -> bool {
+        self.current_slot.is_some()
+            && interval_secs > 0
+            && self.last_saved.elapsed().as_secs() >= interval_secs
+    }
+
+    // Total play time of the current game, including previous sessions
+    fn total_play_time_secs(&self) -> u64 {
+        self.play_time_secs + self.session_started.elapsed().as_secs()
+    }
+
+    // Make a slot name for a new character that doesn't clash with an existing save
+    pub fn new_slot_name(&self, player_name: &str) -> String {
+        let base = sanitize_slot_name(player_name).unwrap_or_else(|| DEFAULT_SLOT.to_string());
+
+        let mut slot = base.clone();
+        let mut suffix = 2;
+
+        while find_slot(&self.saves_dir, &slot).is_some() {
+            slot = format!("{}_{}", base, suffix);
+            suffix += 1;
+        }
+
+        slot
+    }
+
+    // Read every save slot in the saves directory, sorted by most recently saved
+    pub fn refresh_slots(&mut self) {
+        self.slots.clear();
+        self.selected_slot = None;
+
+        let entries = match fs::read_dir(&self.saves_dir) {
+            Ok(entries) => entries,
+            Err(e) => {
+                if e.kind() != std::io::ErrorKind::NotFound {
+                    log::error!("Failed to read saves directory: {}", e);
+                }
+                return;
+            }
+        };
+
+        for entry in entries.flatten() {
+            let path = entry.path();
+
+            let is_save = [
+                super::config::SaveFormat::Json,
+                super::config::SaveFormat::Binary,
+            ]
+            .iter()
+            .any(|format| {
+                path.extension().and_then(|extension| extension.to_str())
+                    == Some(format.extension())
+            });
+
+            if !is_save {
+                continue;
+            }
+
+            let Some(name) = path.file_stem().and_then(|stem| stem.to_str()) else {
+                continue;
+            };
+
+            // Only the metadata is needed, the rest of the save is ignored
+            let metadata = read_save_file(&path)
+                .ok()
+                .and_then(|save| serde_json::from_value::<SlotHeader>(save).ok())
+                .and_then(|header| header.metadata);
+
+            self.slots.push(SaveSlot {
+                name: name.to_string(),
+                metadata,
+            });
         }
+
+        self.slots.sort_by(|a, b| {
+            let saved_at = |slot: &SaveSlot| slot.metadata.as_ref().map_or(0, |m| m.saved_at);
+            saved_at(b).cmp(&saved_at(a)).then(a.name.cmp(&b.name))
+        });
+    }
+
+    // Get the slot selected in the load browser
+    pub fn selected(&self) -> Option<&SaveSlot> {
+        self.slots.get(self.selected_slot?)
     }
 
-    // Save the game to JSON
+    // Delete a save slot and its backups
+    pub fn delete_slot(&mut self, slot: &str) -> Result<(), std::io::Error> {
+        let save_path = find_slot(&self.saves_dir, slot).ok_or(std::io::ErrorKind::NotFound)?;
+
+        fs::remove_file(&save_path)?;
+
+        // Every backup on disk, including any kept before save_backups was lowered
+        for (_, backup_path) in backup_paths(&save_path) {
+            fs::remove_file(backup_path)?;
+        }
+
+        if self.current_slot.as_deref() == Some(slot) {
+            self.current_slot = None;
+        }
+
+        self.refresh_slots();
+
+        Ok(())
+    }
+
+    // Replace a slot with its newest backup that passes the checksum, returns which backup was used
+    pub fn restore_backup(&self, slot: &str) -> Result<usize, Box<dyn std::error::Error>> {
+        let save_path = find_slot(&self.saves_dir, slot).ok_or("Save not found.")?;
+
+        for (backup, backup_path) in backup_paths(&save_path) {
+            let intact = read_save_file(&backup_path)
+                .map(|mut backup_save| verify_checksum(&mut backup_save))
+                .unwrap_or(false);
+
+            if intact {
+                fs::copy(&backup_path, &save_path)?;
+
+                return Ok(backup);
+            }
+        }
+
+        Err(format!("No intact backup of {} was found.", slot).into())
+    }
+
+    // Rename a save slot, returns the name actually used
+    pub fn rename_slot(
+        &mut self,
+        slot: &str,
+        new_name: &str,
+    ) -> Result<String, Box<dyn std::error::Error>> {
+        let new_slot = sanitize_slot_name(new_name).ok_or("Slot name can't be empty.")?;
+
+        if new_slot != slot && find_slot(&self.saves_dir, &new_slot).is_some() {
+            return Err(format!("A save called {} already exists.", new_slot).into());
+        }
+
+        let save_path = find_slot(&self.saves_dir, slot).ok_or("Save not found.")?;
+        let new_save_path = save_path.with_file_name(format!(
+            "{}.{}",
+            new_slot,
+            save_path
+                .extension()
+                .and_then(|extension| extension.to_str())
+                .unwrap_or_default()
+        ));
+
+        fs::rename(&save_path, &new_save_path)?;
+
+        // Backups follow the slot, including any kept before save_backups was lowered
+        for (backup, backup_path) in backup_paths(&save_path) {
+            fs::rename(backup_path, self::backup_path(&new_save_path, backup))?;
+        }
+
+        if self.current_slot.as_deref() == Some(slot) {
+            self.current_slot = Some(new_slot.clone());
+        }
+
+        self.refresh_slots();
+
+        Ok(new_slot)
+    }
+
+    // Path the current game saves to
+    pub fn current_save_path(&self) -> PathBuf {
+        slot_path(
+            &self.saves_dir,
+            self.current_slot.as_deref().unwrap_or(DEFAULT_SLOT),
+            &self.format,
+        )
+    }
+
+    // Save the game in the configured format
     pub fn save(
         &mut self,
         world_manager: &crate::world::manager::WorldManager,
         time_manager: &crate::world::time::TimeManager,
         weather_manager: &crate::world::weather::WeatherManager,
+        dialogue_manager: &crate::ai::dialogue::DialogueManager,
     ) -> Result<(), std::io::Error> {
         // Save player
         if let Some(player) = &world_manager.player {
@@ -51,30 +266,113 @@ impl SaveGameManager {
             log::error!("Failed to save GameWeather: No GameWeather found.")
         }
 
-        // Serialize JSON
-        let json = serde_json::to_string_pretty(&self.save_data)?;
+        // Save format version
+        self.save_data.version = super::migrations::CURRENT_VERSION;
+
+        // Save which world the game was played in
+        self.save_data.world = world_manager.world_identity.clone();
+
+        // Save world seed and looted containers
+        self.save_data.world_seed = world_manager.world_seed;
+        self.save_data.containers = world_manager.container_contents.clone();
+        self.save_data.shops = world_manager.shop_stock.clone();
 
-        // Path to save JSON file
-        let save_path = PathBuf::from("saves").join("save.json");
+        // Save the route and progress of a journey in progress
+        self.save_data.journey = world_manager.journey.clone();
+
+        // Save NPC dialogue memories
+        self.save_data.dialogue_memory = dialogue_manager.memories.clone();
+
+        // Save slot metadata for the load browser
+        self.save_data.metadata = Some(SaveMetadata {
+            player_name: world_manager
+                .player
+                .as_ref()
+                .map(|player| player.name.clone())
+                .unwrap_or_default(),
+            town_name: world_manager
+                .player
+                .as_ref()
+                .map(|player| player.town_name.clone())
+                .unwrap_or_default(),
+            day: self.save_data.time.as_ref().map_or(1, |time| time.day),
+            saved_at: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |duration| duration.as_secs()),
+            play_time_secs: self.total_play_time_secs(),
+        });
+
+        // Serialize, with a checksum so corruption can be spotted on load
+        let mut save_value = serde_json::to_value(&self.save_data)?;
+        let checksum = checksum(&save_value);
+
+        if let serde_json::Value::Object(save_object) = &mut save_value {
+            save_object.insert(CHECKSUM_FIELD.into(), serde_json::Value::from(checksum));
+        }
+
+        let bytes = match self.format {
+            super::config::SaveFormat::Json => serde_json::to_vec_pretty(&save_value)?,
+            super::config::SaveFormat::Binary => rmp_serde::to_vec(&save_value)
+                .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?,
+        };
+
+        // Path to save file
+        let slot = self
+            .current_slot
+            .get_or_insert_with(|| DEFAULT_SLOT.to_string())
+            .clone();
+        let save_path = slot_path(&self.saves_dir, &slot, &self.format);
 
         // Check directory exists and create if it doesn't
         if let Some(parent) = save_path.parent() {
             fs::create_dir_all(parent)?;
         }
 
-        // Write to file
-        fs::write(&save_path, json)?;
+        // Write to a temp file first so a crash mid-write can't corrupt the save
+        let mut temp_path = save_path.clone().into_os_string();
+        temp_path.push(".tmp");
+        let temp_path = PathBuf::from(temp_path);
+        fs::write(&temp_path, bytes)?;
+
+        // Keep the previous saves as backups
+        if save_path.exists() {
+            rotate_backups(&save_path, self.backups)?;
+        }
+
+        // Swap the new save into place
+        fs::rename(&temp_path, &save_path)?;
+
+        // Don't leave a stale copy behind if the save format was changed
+        if let Some(old_path) = find_slot(&self.saves_dir, &slot).filter(|path| path != &save_path)
+        {
+            fs::remove_file(old_path)?;
+        }
+
+        self.last_saved = Instant::now();
 
         Ok(())
     }
 
-    // Loads saved data from JSON
-    pub fn load(&self) -> Result<SaveData, Box<dyn std::error::Error>> {
-        let load_path = PathBuf::from("saves").join("save.json");
+    // Loads saved data from a slot, checking it hasn't been corrupted unless told not to
+    pub fn load(&self, slot: &str, verify: bool) -> Result<SaveData, Box<dyn std::error::Error>> {
+        let load_path = find_slot(&self.saves_dir, slot).ok_or("Save not found.")?;
 
-        let json_data = fs::read_to_string(load_path)?;
+        let mut save_json = read_save_file(&load_path)?;
 
-        let save_data: SaveData = serde_json::from_str(&json_data)?;
+        if !verify_checksum(&mut save_json) {
+            if verify {
+                return Err(Box::new(ChecksumMismatch {
+                    slot: slot.to_string(),
+                }));
+            }
+
+            log::error!("Loading save {} despite a checksum mismatch", slot);
+        }
+
+        // Bring older saves up to date before reading them
+        let version = super::migrations::migrate(&mut save_json)?;
+
+        let save_data = SaveData::from_json(save_json, version)?;
 
         Ok(save_data)
     }
@@ -83,9 +381,25 @@ impl SaveGameManager {
 // Struct for Save Data
 #[derive(Debug, Serialize, Deserialize)]
 pub struct SaveData {
+    #[serde(default)]
+    pub version: u32,
     pub player: Option<crate::entities::player::Player>,
     pub time: Option<crate::world::time::GameTime>,
     pub weather: Option<crate::world::weather::GameWeather>,
+    #[serde(default)]
+    pub world_seed: u64,
+    #[serde(default)]
+    pub containers: HashMap<u32, Vec<crate::entities::inventory::ItemStack>>,
+    #[serde(default)]
+    pub shops: HashMap<u32, crate::world::shop::ShopStock>,
+    #[serde(default)]
+    pub dialogue_memory: HashMap<u32, crate::ai::memory::DialogueMemory>,
+    #[serde(default)]
+    pub metadata: Option<SaveMetadata>,
+    #[serde(default)]
+    pub world: Option<crate::world::manager::WorldIdentity>,
+    #[serde(default)]
+    pub journey: Option<crate::world::journey::Journey>,
 }
 
 // Functions for Save Data
@@ -93,9 +407,752 @@ impl SaveData {
     // Create a new Save Data
     fn new() -> Self {
         Self {
+            version: super::migrations::CURRENT_VERSION,
             player: None,
             time: None,
             weather: None,
+            world_seed: 0,
+            containers: HashMap::new(),
+            shops: HashMap::new(),
+            dialogue_memory: HashMap::new(),
+            metadata: None,
+            world: None,
+            journey: None,
         }
     }
+
+    // Read migrated save JSON one field at a time so an error can name the field at fault
+    // The version is the one the save was written with, for reporting errors
+    pub(super) fn from_json(
+        save_json: serde_json::Value,
+        version: u32,
+    ) -> Result<Self, super::migrations::LoadError> {
+        let serde_json::Value::Object(mut save_json) = save_json else {
+            return Err(super::migrations::LoadError::new(
+                version,
+                None,
+                "save is not a JSON object",
+            ));
+        };
+
+        let mut field = |name: &str| save_json.remove(name).unwrap_or_default();
+
+        let player: Option<crate::entities::player::Player> =
+            read_field("player", field("player"), version)?;
+
+        if let Some(player) = &player {
+            player
+                .character
+                .attributes
+                .validate()
+                .map_err(|e| super::migrations::LoadError::new(version, Some("player"), &e))?;
+        }
+
+        Ok(Self {
+            version: super::migrations::CURRENT_VERSION,
+            player,
+            time: read_field("time", field("time"), version)?,
+            weather: read_field("weather", field("weather"), version)?,
+            world_seed: read_field("world_seed", field("world_seed"), version)?,
+            containers: read_field("containers", field("containers"), version)?,
+            shops: read_field("shops", field("shops"), version)?,
+            dialogue_memory: read_field("dialogue_memory", field("dialogue_memory"), version)?,
+            metadata: read_field("metadata", field("metadata"), version)?,
+            world: read_field("world", field("world"), version)?,
+            journey: read_field("journey", field("journey"), version)?,
+        })
+    }
+}
+
+// Deserialize a single field of the save, missing fields use their default
+fn read_field<T: serde::de::DeserializeOwned + Default>(
+    name: &str,
+    value: serde_json::Value,
+    version: u32,
+) -> Result<T, super::migrations::LoadError> {
+    if value.is_null() {
+        return Ok(T::default());
+    }
+
+    serde_json::from_value(value)
+        .map_err(|e| super::migrations::LoadError::new(version, Some(name), &e.to_string()))
+}
+
+// Struct for the details shown about a save in the load browser
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SaveMetadata {
+    pub player_name: String,
+    pub town_name: String,
+    pub day: u32,
+    pub saved_at: u64,
+    pub play_time_secs: u64,
+}
+
+// Functions for Save Metadata
+impl SaveMetadata {
+    // When the save was made, e.g. 2025-03-01 18:42 UTC
+    pub fn saved_at_display(&self) -> String {
+        let days = (self.saved_at / 86_400) as i64;
+        let secs_of_day = self.saved_at % 86_400;
+
+        // Convert days since the epoch to a civil date
+        let z = days + 719_468;
+        let era = z.div_euclid(146_097);
+        let day_of_era = z.rem_euclid(146_097);
+        let year_of_era =
+            (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let month_index = (5 * day_of_year + 2) / 153;
+        let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+        let month = if month_index < 10 {
+            month_index + 3
+        } else {
+            month_index - 9
+        };
+        let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+
+        format!(
+            "{:04}-{:02}-{:02} {:02}:{:02} UTC",
+            year,
+            month,
+            day,
+            secs_of_day / 3600,
+            secs_of_day % 3600 / 60
+        )
+    }
+
+    // Total play time, e.g. 1h 05m
+    pub fn play_time_display(&self) -> String {
+        format!(
+            "{}h {:02}m",
+            self.play_time_secs / 3600,
+            self.play_time_secs % 3600 / 60
+        )
+    }
+}
+
+// Struct for a save file found in the saves directory
+pub struct SaveSlot {
+    pub name: String,
+    pub metadata: Option<SaveMetadata>,
+}
+
+// Struct for reading just the metadata from a save file
+#[derive(Deserialize)]
+struct SlotHeader {
+    #[serde(default)]
+    metadata: Option<SaveMetadata>,
+}
+
+// Struct for a save whose checksum doesn't match its contents
+#[derive(Debug)]
+pub struct ChecksumMismatch {
+    slot: String,
+}
+
+impl std::fmt::Display for ChecksumMismatch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Save {} has been changed or corrupted since it was written",
+            self.slot
+        )
+    }
+}
+
+impl std::error::Error for ChecksumMismatch {}
+
+// Struct for a save made against a different world to the one that would be loaded
+#[derive(Debug, Clone)]
+pub struct WorldMismatch {
+    pub saved: crate::world::manager::WorldIdentity,
+    pub current: crate::world::manager::WorldIdentity,
+    pub matching_available: bool,
+    pub verify: bool,
+}
+
+impl std::fmt::Display for WorldMismatch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Save was made with the world in {} ({}) but the world in {} ({}) would be loaded",
+            self.saved.path, self.saved.hash, self.current.path, self.current.hash
+        )
+    }
+}
+
+impl std::error::Error for WorldMismatch {}
+
+// Const for the field the checksum is stored under
+const CHECKSUM_FIELD: &str = "checksum";
+
+// Checksum of a save, over its compact JSON
+fn checksum(save: &serde_json::Value) -> String {
+    super::hash::fnv1a(save.to_string().as_bytes())
+}
+
+// Remove the stored checksum from a save and check it against the contents
+// Saves from before checksums existed have nothing to check and pass
+fn verify_checksum(save: &mut serde_json::Value) -> bool {
+    let Some(save_object) = save.as_object_mut() else {
+        return false;
+    };
+
+    match save_object.remove(CHECKSUM_FIELD) {
+        Some(stored) => stored.as_str() == Some(checksum(save).as_str()),
+        None => true,
+    }
+}
+
+// Read a save file in either format
+fn read_save_file(path: &std::path::Path) -> Result<serde_json::Value, Box<dyn std::error::Error>> {
+    let bytes = fs::read(path)?;
+
+    // Binary saves (and their backups) are MessagePack, anything else is JSON
+    let is_binary = path
+        .file_name()
+        .and_then(|file_name| file_name.to_str())
+        .and_then(|file_name| file_name.split('.').nth(1))
+        == Some(super::config::SaveFormat::Binary.extension());
+
+    if is_binary {
+        Ok(rmp_serde::from_slice(&bytes)?)
+    } else {
+        Ok(serde_json::from_slice(&bytes)?)
+    }
+}
+
+// Path to a slot's save file in a given format
+fn slot_path(
+    saves_dir: &std::path::Path,
+    slot: &str,
+    format: &super::config::SaveFormat,
+) -> PathBuf {
+    saves_dir.join(format!("{}.{}", slot, format.extension()))
+}
+
+// Find a slot's save file, whichever format it was written in
+fn find_slot(saves_dir: &std::path::Path, slot: &str) -> Option<PathBuf> {
+    [
+        super::config::SaveFormat::Json,
+        super::config::SaveFormat::Binary,
+    ]
+    .iter()
+    .map(|format| slot_path(saves_dir, slot, format))
+    .find(|path| path.exists())
+}
+
+// Path to a numbered backup of a save file, e.g. saves/hero.json.bak1
+fn backup_path(save_path: &std::path::Path, backup: usize) -> PathBuf {
+    let mut path = save_path.as_os_str().to_owned();
+    path.push(format!(".bak{}", backup));
+
+    PathBuf::from(path)
+}
+
+// Every numbered backup of a save file on disk, oldest last
+fn backup_paths(save_path: &std::path::Path) -> Vec<(usize, PathBuf)> {
+    let (Some(dir), Some(file_name)) = (
+        save_path.parent(),
+        save_path
+            .file_name()
+            .and_then(|file_name| file_name.to_str()),
+    ) else {
+        return Vec::new();
+    };
+
+    let prefix = format!("{}.bak", file_name);
+
+    let mut backups: Vec<(usize, PathBuf)> = fs::read_dir(dir)
+        .map(|entries| {
+            entries
+                .flatten()
+                .filter_map(|entry| {
+                    let backup = entry
+                        .file_name()
+                        .to_str()?
+                        .strip_prefix(&prefix)?
+                        .parse()
+                        .ok()?;
+
+                    Some((backup, entry.path()))
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    backups.sort();
+
+    backups
+}
+
+// Shift each backup along by one, dropping the oldest, and copy the current save in as the newest
+fn rotate_backups(save_path: &std::path::Path, backups: usize) -> Result<(), std::io::Error> {
+    // Drop backups past the limit, left over from before save_backups was lowered
+    for (backup, backup_path) in backup_paths(save_path) {
+        if backup > backups {
+            fs::remove_file(backup_path)?;
+        }
+    }
+
+    if backups == 0 {
+        return Ok(());
+    }
+
+    for backup in (1..backups).rev() {
+        let from = backup_path(save_path, backup);
+
+        if from.exists() {
+            fs::rename(from, backup_path(save_path, backup + 1))?;
+        }
+    }
+
+    // Copied rather than moved so there's always a complete save in place
+    fs::copy(save_path, backup_path(save_path, 1))?;
+
+    Ok(())
+}
+
+// Turn a name into something safe to use as a file name, None if nothing is left
+fn sanitize_slot_name(name: &str) -> Option<String> {
+    let slot: String = name
+        .trim()
+        .chars()
+        .map(|c| {
+            if c.is_alphanumeric() || c == '-' || c == '_' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect();
+
+    if slot.is_empty() {
+        None
+    } else {
+        Some(slot)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::config::{Config, SaveFormat};
+
+    // A save manager writing to a scratch saves directory of its own
+    fn save_manager(name: &str, backups: usize, format: SaveFormat) -> SaveGameManager {
+        let config = Config {
+            save_backups: backups,
+            save_format: format,
+            ..Config::default()
+        };
+
+        let mut save_manager = SaveGameManager::new(&config);
+        save_manager.saves_dir = std::env::temp_dir().join(format!(
+            "cli-town-explorer-saves-{}-{}",
+            std::process::id(),
+            name
+        ));
+        let _ = fs::remove_dir_all(&save_manager.saves_dir);
+
+        save_manager
+    }
+
+    // Save a game whose player has the given gold, so each save can be told apart
+    fn save_with_gold(save_manager: &mut SaveGameManager, gold: u32) {
+        let config = Config::default();
+        let mut world_manager = crate::world::manager::WorldManager::new();
+        world_manager.player = Some(crate::entities::player::Player::new(
+            1,
+            "Ada".into(),
+            "Ash Burhwich".into(),
+            gold,
+            Default::default(),
+        ));
+
+        save_manager
+            .save(
+                &world_manager,
+                &crate::world::time::TimeManager::new(),
+                &crate::world::weather::WeatherManager::new(),
+                &crate::ai::dialogue::DialogueManager::new(&config),
+            )
+            .unwrap();
+    }
+
+    fn gold_in(path: &std::path::Path) -> u64 {
+        read_save_file(path).unwrap()["player"]["gold"]
+            .as_u64()
+            .unwrap()
+    }
+
+    fn files_in(dir: &std::path::Path) -> Vec<String> {
+        let mut files: Vec<String> = fs::read_dir(dir)
+            .unwrap()
+            .flatten()
+            .map(|entry| entry.file_name().to_string_lossy().into_owned())
+            .collect();
+        files.sort();
+
+        files
+    }
+
+    #[test]
+    fn keeps_the_configured_number_of_rotating_backups() {
+        let backups = 3;
+        let mut save_manager = save_manager("rotation", backups, SaveFormat::Json);
+        save_manager.begin_session("hero".into(), 0);
+
+        for gold in 1..=backups as u32 + 2 {
+            save_with_gold(&mut save_manager, gold);
+        }
+
+        let save_path = save_manager.current_save_path();
+        assert_eq!(gold_in(&save_path), backups as u64 + 2);
+
+        for backup in 1..=backups {
+            assert_eq!(
+                gold_in(&backup_path(&save_path, backup)),
+                (backups + 2 - backup) as u64
+            );
+        }
+
+        assert_eq!(
+            files_in(&save_manager.saves_dir),
+            [
+                "hero.json",
+                "hero.json.bak1",
+                "hero.json.bak2",
+                "hero.json.bak3"
+            ]
+        );
+
+        fs::remove_dir_all(&save_manager.saves_dir).unwrap();
+    }
+
+    #[test]
+    fn keeps_no_backups_when_told_not_to() {
+        let mut save_manager = save_manager("no-backups", 0, SaveFormat::Json);
+        save_manager.begin_session("hero".into(), 0);
+
+        save_with_gold(&mut save_manager, 1);
+        save_with_gold(&mut save_manager, 2);
+
+        assert_eq!(files_in(&save_manager.saves_dir), ["hero.json"]);
+        assert_eq!(gold_in(&save_manager.current_save_path()), 2);
+
+        fs::remove_dir_all(&save_manager.saves_dir).unwrap();
+    }
+
+    #[test]
+    fn writes_whole_saves_through_a_temp_file() {
+        let mut save_manager = save_manager("atomic", 2, SaveFormat::Json);
+        save_manager.begin_session("hero".into(), 0);
+        save_with_gold(&mut save_manager, 1);
+
+        // Left over from a write that never finished
+        let save_path = save_manager.current_save_path();
+        let mut temp_path = save_path.clone().into_os_string();
+        temp_path.push(".tmp");
+        fs::write(&temp_path, "{ \"player\": ").unwrap();
+
+        save_with_gold(&mut save_manager, 2);
+
+        assert!(!std::path::Path::new(&temp_path).exists());
+        assert_eq!(gold_in(&save_path), 2);
+        assert_eq!(gold_in(&backup_path(&save_path, 1)), 1);
+        assert!(save_manager.load("hero", true).is_ok());
+
+        fs::remove_dir_all(&save_manager.saves_dir).unwrap();
+    }
+
+    #[test]
+    fn autosaves_are_due_only_during_a_game_after_the_interval() {
+        let mut save_manager = save_manager("autosave", 1, SaveFormat::Json);
+        let a_while_ago = Instant::now() - std::time::Duration::from_secs(2);
+
+        save_manager.last_saved = a_while_ago;
+        assert!(!save_manager.autosave_due(1));
+
+        save_manager.begin_session("hero".into(), 0);
+        assert!(!save_manager.autosave_due(1));
+
+        save_manager.last_saved = a_while_ago;
+        assert!(save_manager.autosave_due(1));
+        assert!(!save_manager.autosave_due(0));
+
+        save_with_gold(&mut save_manager, 1);
+        assert!(!save_manager.autosave_due(1));
+
+        save_manager.last_saved = a_while_ago;
+        save_manager.end_session();
+        assert!(!save_manager.autosave_due(1));
+
+        fs::remove_dir_all(&save_manager.saves_dir).unwrap();
+    }
+
+    // Rewrite part of a JSON save on disk without updating its checksum
+    fn tamper(path: &std::path::Path, from: &str, to: &str) {
+        let contents = fs::read_to_string(path).unwrap();
+        assert!(contents.contains(from));
+
+        fs::write(path, contents.replacen(from, to, 1)).unwrap();
+    }
+
+    #[test]
+    fn a_fresh_save_passes_its_checksum() {
+        let mut save_manager = save_manager("checksum", 1, SaveFormat::Json);
+        save_manager.begin_session("hero".into(), 0);
+        save_with_gold(&mut save_manager, 7);
+
+        let mut save_json = read_save_file(&save_manager.current_save_path()).unwrap();
+        assert!(save_json[CHECKSUM_FIELD].is_string());
+        assert!(verify_checksum(&mut save_json));
+
+        let save = save_manager.load("hero", true).unwrap();
+        assert_eq!(save.player.unwrap().gold, 7);
+
+        fs::remove_dir_all(&save_manager.saves_dir).unwrap();
+    }
+
+    #[test]
+    fn a_one_byte_edit_fails_the_checksum() {
+        let mut save_manager = save_manager("tamper", 1, SaveFormat::Json);
+        save_manager.begin_session("hero".into(), 0);
+        save_with_gold(&mut save_manager, 7);
+
+        tamper(
+            &save_manager.current_save_path(),
+            "\"gold\": 7",
+            "\"gold\": 8",
+        );
+
+        let error = save_manager.load("hero", true).unwrap_err();
+        assert!(error.downcast_ref::<ChecksumMismatch>().is_some());
+        assert_eq!(
+            error.to_string(),
+            "Save hero has been changed or corrupted since it was written"
+        );
+
+        // Loading anyway is still possible when the player asks for it
+        assert_eq!(
+            save_manager
+                .load("hero", false)
+                .unwrap()
+                .player
+                .unwrap()
+                .gold,
+            8
+        );
+
+        fs::remove_dir_all(&save_manager.saves_dir).unwrap();
+    }
+
+    #[test]
+    fn restores_the_newest_intact_backup() {
+        let mut save_manager = save_manager("restore", 3, SaveFormat::Json);
+        save_manager.begin_session("hero".into(), 0);
+
+        for gold in 1..=3 {
+            save_with_gold(&mut save_manager, gold);
+        }
+
+        let save_path = save_manager.current_save_path();
+        tamper(&save_path, "\"gold\": 3", "\"gold\": 9");
+        tamper(&backup_path(&save_path, 1), "\"gold\": 2", "\"gold\": 9");
+
+        assert_eq!(save_manager.restore_backup("hero").unwrap(), 2);
+        assert_eq!(
+            save_manager
+                .load("hero", true)
+                .unwrap()
+                .player
+                .unwrap()
+                .gold,
+            1
+        );
+
+        tamper(&backup_path(&save_path, 2), "\"gold\": 1", "\"gold\": 9");
+        assert_eq!(
+            save_manager.restore_backup("hero").unwrap_err().to_string(),
+            "No intact backup of hero was found."
+        );
+
+        fs::remove_dir_all(&save_manager.saves_dir).unwrap();
+    }
+
+    #[test]
+    fn a_binary_save_loads_the_same_as_json() {
+        let mut json_manager = save_manager("format-json", 1, SaveFormat::Json);
+        let mut binary_manager = save_manager("format-binary", 1, SaveFormat::Binary);
+
+        for save_manager in [&mut json_manager, &mut binary_manager] {
+            save_manager.begin_session("hero".into(), 0);
+            save_with_gold(save_manager, 7);
+        }
+
+        let binary_path = binary_manager.current_save_path();
+        assert_eq!(binary_path.extension().unwrap(), "sav");
+        assert!(
+            serde_json::from_slice::<serde_json::Value>(&fs::read(&binary_path).unwrap()).is_err()
+        );
+
+        let mut json_save = json_manager.load("hero", true).unwrap();
+        let mut binary_save = binary_manager.load("hero", true).unwrap();
+
+        // Only when each was written can differ
+        json_save.metadata = None;
+        binary_save.metadata = None;
+
+        assert_eq!(
+            serde_json::to_value(&json_save).unwrap(),
+            serde_json::to_value(&binary_save).unwrap()
+        );
+
+        // The checksum covers binary saves too
+        let mut save_json = read_save_file(&binary_path).unwrap();
+        save_json["player"]["gold"] = serde_json::Value::from(8);
+        fs::write(&binary_path, rmp_serde::to_vec(&save_json).unwrap()).unwrap();
+
+        assert!(binary_manager
+            .load("hero", true)
+            .unwrap_err()
+            .downcast_ref::<ChecksumMismatch>()
+            .is_some());
+
+        fs::remove_dir_all(&json_manager.saves_dir).unwrap();
+        fs::remove_dir_all(&binary_manager.saves_dir).unwrap();
+    }
+
+    #[test]
+    fn lists_slots_newest_first_with_their_metadata() {
+        let mut save_manager = save_manager("list", 1, SaveFormat::Json);
+
+        for (slot, gold) in [("alpha", 1), ("bravo", 2)] {
+            save_manager.begin_session(slot.into(), 0);
+            save_with_gold(&mut save_manager, gold);
+        }
+
+        // Not a save, so not a slot
+        fs::write(save_manager.saves_dir.join("notes.txt"), "").unwrap();
+
+        save_manager.refresh_slots();
+
+        let names: Vec<&str> = save_manager
+            .slots
+            .iter()
+            .map(|slot| slot.name.as_str())
+            .collect();
+        let mut expected = vec!["alpha", "bravo"];
+        expected.sort_by_key(|name| {
+            let saved_at = save_manager
+                .slots
+                .iter()
+                .find(|slot| slot.name == *name)
+                .and_then(|slot| slot.metadata.as_ref())
+                .unwrap()
+                .saved_at;
+
+            (std::cmp::Reverse(saved_at), *name)
+        });
+
+        assert_eq!(names, expected);
+        assert!(save_manager.slots.iter().all(|slot| {
+            let metadata = slot.metadata.as_ref().unwrap();
+            metadata.player_name == "Ada" && metadata.town_name == "Ash Burhwich"
+        }));
+
+        fs::remove_dir_all(&save_manager.saves_dir).unwrap();
+    }
+
+    // Make four backups of a slot, then lower the setting to one as if config.json was edited
+    fn slot_with_stale_backups(name: &str) -> SaveGameManager {
+        let mut save_manager = save_manager(name, 4, SaveFormat::Json);
+        save_manager.begin_session("hero".into(), 0);
+
+        for gold in 1..=5 {
+            save_with_gold(&mut save_manager, gold);
+        }
+
+        save_manager.backups = 1;
+
+        save_manager
+    }
+
+    #[test]
+    fn deleting_a_slot_removes_every_backup() {
+        let mut save_manager = slot_with_stale_backups("delete");
+        save_manager.begin_session("other".into(), 0);
+        save_with_gold(&mut save_manager, 1);
+
+        save_manager.delete_slot("hero").unwrap();
+
+        assert_eq!(files_in(&save_manager.saves_dir), ["other.json"]);
+        assert_eq!(save_manager.slots.len(), 1);
+        assert!(save_manager.delete_slot("hero").is_err());
+
+        fs::remove_dir_all(&save_manager.saves_dir).unwrap();
+    }
+
+    #[test]
+    fn renaming_a_slot_takes_every_backup_with_it() {
+        let mut save_manager = slot_with_stale_backups("rename");
+
+        assert_eq!(
+            save_manager.rename_slot("hero", "Sir Hero").unwrap(),
+            "Sir_Hero"
+        );
+        assert_eq!(save_manager.current_slot.as_deref(), Some("Sir_Hero"));
+        assert_eq!(
+            files_in(&save_manager.saves_dir),
+            [
+                "Sir_Hero.json",
+                "Sir_Hero.json.bak1",
+                "Sir_Hero.json.bak2",
+                "Sir_Hero.json.bak3",
+                "Sir_Hero.json.bak4"
+            ]
+        );
+        assert_eq!(
+            gold_in(&save_manager.saves_dir.join("Sir_Hero.json.bak4")),
+            1
+        );
+
+        // The next save drops the backups past the lowered limit
+        save_with_gold(&mut save_manager, 6);
+        assert_eq!(
+            files_in(&save_manager.saves_dir),
+            ["Sir_Hero.json", "Sir_Hero.json.bak1"]
+        );
+
+        fs::remove_dir_all(&save_manager.saves_dir).unwrap();
+    }
+
+    #[test]
+    fn rejects_renaming_onto_another_slot() {
+        let mut save_manager = save_manager("rename-clash", 1, SaveFormat::Json);
+
+        for slot in ["hero", "villain"] {
+            save_manager.begin_session(slot.into(), 0);
+            save_with_gold(&mut save_manager, 1);
+        }
+
+        assert_eq!(
+            save_manager
+                .rename_slot("hero", "villain")
+                .unwrap_err()
+                .to_string(),
+            "A save called villain already exists."
+        );
+        assert_eq!(
+            save_manager
+                .rename_slot("hero", "  ")
+                .unwrap_err()
+                .to_string(),
+            "Slot name can't be empty."
+        );
+        assert_eq!(save_manager.rename_slot("hero", "hero").unwrap(), "hero");
+        assert_eq!(save_manager.new_slot_name("villain"), "villain_2");
+
+        fs::remove_dir_all(&save_manager.saves_dir).unwrap();
+    }
 }
@@ -4,15 +4,46 @@ pub enum StateType {
     MainMenu,
     Name,
     NameConfirm,
+    CreateRace,
+    CreateSex,
+    CreateBackground,
+    CreateMethod,
+    CreatePointBuy,
+    CreateRoll,
+    WorldSelect,
+    TownSelect,
     Game,
     GameSaveSuccess,
     GameSaveError,
     GameLoadError,
     GameInitError,
     GameQuit,
+    LoadGame,
+    LoadSlot,
+    LoadSlotRename,
+    LoadSlotDelete,
+    LoadChecksumMismatch,
+    LoadWorldMismatch,
     Time,
     Weather,
     Travel,
+    Journey,
+    JourneyEncounter,
+    Map,
+    Explore,
+    TownMap,
+    Building,
+    Room,
+    Conversation,
+    Container,
+    Shop,
+    ShopBuy,
+    ShopSell,
+    Tavern,
+    TavernRumours,
+    Temple,
+    Inventory,
+    InventoryItem,
 }
 
 // Struct for State Manager
@@ -0,0 +1,324 @@
+use serde::{Deserialize, Serialize};
+use strum::IntoEnumIterator;
+use strum_macros::EnumIter;
+
+use crate::world::manager::{NpcRace, NpcSex};
+
+// Consts for attribute points, every attribute starts at the minimum and point buy has points to spend on top
+pub const ATTRIBUTE_MIN: u32 = 3;
+pub const ATTRIBUTE_MAX: u32 = 8;
+pub const POINT_BUY_POINTS: u32 = 10;
+
+// Consts for rolling attributes, 1d6+2 gives the same range as point buy, and how many rerolls are allowed
+pub const ATTRIBUTE_DICE: crate::core::dice::DiceRoll =
+    crate::core::dice::DiceRoll::new(1, 6, ATTRIBUTE_MIN as i32 - 1);
+pub const DICE_REROLLS: u32 = 2;
+
+// Salt for the creation dice, so they don't replay the same rolls as the world's dice
+const CREATION_DICE_SALT: u64 = 0xD1B5_4A32_D192_ED03;
+
+// Enum for attributes
+#[derive(Debug, Clone, Copy, PartialEq, EnumIter)]
+pub enum Attribute {
+    Strength,
+    Agility,
+    Wit,
+    Charm,
+}
+
+// Enum for backgrounds, each one adds a point to an attribute
+#[derive(Debug, Clone, Copy, PartialEq, Default, EnumIter, Serialize, Deserialize)]
+pub enum Background {
+    #[default]
+    Soldier,
+    Scout,
+    Scholar,
+    Merchant,
+}
+
+// Functions for Background
+impl Background {
+    pub fn description(&self) -> &'static str {
+        match self {
+            Background::Soldier => "Years in a garrison left you hardy and strong.",
+            Background::Scout => "You know the roads and how to move quietly along them.",
+            Background::Scholar => "Books and ledgers taught you to think quickly.",
+            Background::Merchant => {
+                "A life at market stalls taught you to talk your way into a deal."
+            }
+        }
+    }
+
+    pub fn bonus(&self) -> Attribute {
+        match self {
+            Background::Soldier => Attribute::Strength,
+            Background::Scout => Attribute::Agility,
+            Background::Scholar => Attribute::Wit,
+            Background::Merchant => Attribute::Charm,
+        }
+    }
+}
+
+// Struct for a character's attributes
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Attributes {
+    pub strength: u32,
+    pub agility: u32,
+    pub wit: u32,
+    pub charm: u32,
+}
+
+// Functions for Attributes
+impl Attributes {
+    // Create new Attributes with every attribute at the same value
+    pub fn new(value: u32) -> Self {
+        Self {
+            strength: value,
+            agility: value,
+            wit: value,
+            charm: value,
+        }
+    }
+
+    pub fn get(&self, attribute: Attribute) -> u32 {
+        match attribute {
+            Attribute::Strength => self.strength,
+            Attribute::Agility => self.agility,
+            Attribute::Wit => self.wit,
+            Attribute::Charm => self.charm,
+        }
+    }
+
+    // Check every attribute is one character creation could have made, the background bonus can go one past the maximum
+    pub fn validate(&self) -> Result<(), String> {
+        for attribute in Attribute::iter() {
+            let value = self.get(attribute);
+
+            if !(ATTRIBUTE_MIN..=ATTRIBUTE_MAX + 1).contains(&value) {
+                return Err(format!(
+                    "{:?} is {}, attributes go from {} to {}",
+                    attribute,
+                    value,
+                    ATTRIBUTE_MIN,
+                    ATTRIBUTE_MAX + 1
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    fn get_mut(&mut self, attribute: Attribute) -> &mut u32 {
+        match attribute {
+            Attribute::Strength => &mut self.strength,
+            Attribute::Agility => &mut self.agility,
+            Attribute::Wit => &mut self.wit,
+            Attribute::Charm => &mut self.charm,
+        }
+    }
+}
+
+// Average attributes for saves from before character creation
+impl Default for Attributes {
+    fn default() -> Self {
+        Self::new(ATTRIBUTE_MIN + POINT_BUY_POINTS / 4)
+    }
+}
+
+// Struct for who the player is, chosen during character creation
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Character {
+    pub race: NpcRace,
+    pub sex: NpcSex,
+    pub background: Background,
+    pub attributes: Attributes,
+}
+
+// Struct for a character part way through creation
+pub struct CharacterCreation {
+    pub race: NpcRace,
+    pub sex: NpcSex,
+    pub background: Background,
+    pub attributes: Attributes,
+    pub points_left: u32,
+    pub rerolls_left: u32,
+    pub rolls: Vec<crate::core::dice::RollResult>,
+    pub seed: u64,
+    dice: crate::core::dice::Dice,
+}
+
+// Functions for Character Creation
+impl CharacterCreation {
+    // Create a new Character Creation, the seed becomes the new world's seed so the rolls can be replayed
+    pub fn new(seed: u64) -> Self {
+        Self {
+            race: NpcRace::default(),
+            sex: NpcSex::default(),
+            background: Background::default(),
+            attributes: Attributes::new(ATTRIBUTE_MIN),
+            points_left: POINT_BUY_POINTS,
+            rerolls_left: DICE_REROLLS,
+            rolls: Vec::new(),
+            seed,
+            dice: crate::core::dice::Dice::new(seed ^ CREATION_DICE_SALT),
+        }
+    }
+
+    // Start spending points from scratch
+    pub fn start_point_buy(&mut self) {
+        self.attributes = Attributes::new(ATTRIBUTE_MIN);
+        self.points_left = POINT_BUY_POINTS;
+    }
+
+    // Spend a point on an attribute, returns false if there are none left or it's at the maximum
+    pub fn add_point(&mut self, attribute: Attribute) -> bool {
+        let value = self.attributes.get_mut(attribute);
+
+        if self.points_left == 0 || *value >= ATTRIBUTE_MAX {
+            return false;
+        }
+
+        *value += 1;
+        self.points_left -= 1;
+
+        true
+    }
+
+    // Take a point back from an attribute, returns false if it's already at the minimum
+    pub fn remove_point(&mut self, attribute: Attribute) -> bool {
+        let value = self.attributes.get_mut(attribute);
+
+        if *value <= ATTRIBUTE_MIN {
+            return false;
+        }
+
+        *value -= 1;
+        self.points_left += 1;
+
+        true
+    }
+
+    // Start rolling from scratch with a full set of rerolls
+    pub fn start_rolling(&mut self) {
+        self.rerolls_left = DICE_REROLLS;
+        self.roll();
+    }
+
+    // Roll again, returns false if there are no rerolls left
+    pub fn reroll(&mut self) -> bool {
+        if self.rerolls_left == 0 {
+            return false;
+        }
+
+        self.rerolls_left -= 1;
+        self.roll();
+
+        true
+    }
+
+    // Roll every attribute, keeping the rolls to show the player
+    fn roll(&mut self) {
+        self.rolls = (0..4).map(|_| self.dice.roll(ATTRIBUTE_DICE)).collect();
+
+        let total = |index: usize| self.rolls[index].total as u32;
+
+        self.attributes = Attributes {
+            strength: total(0),
+            agility: total(1),
+            wit: total(2),
+            charm: total(3),
+        };
+        self.points_left = 0;
+    }
+
+    // The finished character, with the background's bonus added
+    pub fn character(&self) -> Character {
+        let mut attributes = self.attributes.clone();
+        *attributes.get_mut(self.background.bonus()) += 1;
+
+        Character {
+            race: self.race.clone(),
+            sex: self.sex.clone(),
+            background: self.background,
+            attributes,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn point_buy_stops_at_the_attribute_limits() {
+        let mut creation = CharacterCreation::new(1);
+        creation.start_point_buy();
+
+        assert!(!creation.remove_point(Attribute::Wit));
+        assert_eq!(creation.attributes.wit, ATTRIBUTE_MIN);
+        assert_eq!(creation.points_left, POINT_BUY_POINTS);
+
+        for _ in ATTRIBUTE_MIN..ATTRIBUTE_MAX {
+            assert!(creation.add_point(Attribute::Wit));
+        }
+        assert!(!creation.add_point(Attribute::Wit));
+        assert_eq!(creation.attributes.wit, ATTRIBUTE_MAX);
+        assert_eq!(
+            creation.points_left,
+            POINT_BUY_POINTS - (ATTRIBUTE_MAX - ATTRIBUTE_MIN)
+        );
+
+        assert!(creation.remove_point(Attribute::Wit));
+        assert_eq!(creation.attributes.wit, ATTRIBUTE_MAX - 1);
+        assert_eq!(
+            creation.points_left,
+            POINT_BUY_POINTS - (ATTRIBUTE_MAX - ATTRIBUTE_MIN) + 1
+        );
+    }
+
+    #[test]
+    fn point_buy_stops_when_the_points_run_out() {
+        let mut creation = CharacterCreation::new(1);
+        creation.start_point_buy();
+
+        for attribute in Attribute::iter().cycle().take(POINT_BUY_POINTS as usize) {
+            assert!(creation.add_point(attribute));
+        }
+
+        assert_eq!(creation.points_left, 0);
+        assert!(!creation.add_point(Attribute::Charm));
+        assert!(creation.attributes.validate().is_ok());
+    }
+
+    #[test]
+    fn rerolls_run_out_and_start_again_with_new_rolls() {
+        let mut creation = CharacterCreation::new(7);
+        creation.start_rolling();
+        assert_eq!(creation.rolls.len(), 4);
+
+        for left in (0..DICE_REROLLS).rev() {
+            assert!(creation.reroll());
+            assert_eq!(creation.rerolls_left, left);
+        }
+
+        let last = creation.attributes.clone();
+        assert!(!creation.reroll());
+        assert_eq!(creation.attributes, last);
+
+        creation.start_rolling();
+        assert_eq!(creation.rerolls_left, DICE_REROLLS);
+    }
+
+    #[test]
+    fn the_same_seed_rolls_the_same_character() {
+        let rolled = |seed| {
+            let mut creation = CharacterCreation::new(seed);
+            creation.start_rolling();
+            creation.reroll();
+            creation.attributes
+        };
+
+        assert_eq!(rolled(42), rolled(42));
+        assert!(rolled(42).validate().is_ok());
+    }
+}
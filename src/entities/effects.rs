@@ -0,0 +1,236 @@
+use serde::{Deserialize, Serialize};
+
+use crate::world::time::GameTime;
+
+// Consts for how strongly effects change things
+const FORTUNE_REPUTATION: i32 = 25;
+const WAYFARER_FARE_MODIFIER: f32 = 0.75;
+const CHILLED_TRAVEL_MODIFIER: f32 = 1.25;
+
+// Enum for the kinds of status effect
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum EffectKind {
+    Fortune,
+    Wayfarer,
+    Chilled,
+}
+
+// Functions for Effect Kind
+impl EffectKind {
+    // Name shown to the player
+    pub fn name(&self) -> &'static str {
+        match self {
+            EffectKind::Fortune => "Blessing of Fortune",
+            EffectKind::Wayfarer => "Blessing of the Road",
+            EffectKind::Chilled => "Chilled",
+        }
+    }
+
+    // Whether the effect is bad for the player, these are what healing removes
+    pub fn harmful(&self) -> bool {
+        matches!(self, EffectKind::Chilled)
+    }
+}
+
+// Struct for a single status effect and when it wears off
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StatusEffect {
+    pub kind: EffectKind,
+    pub expires_day: u32,
+    pub expires_tick: u32,
+}
+
+// Functions for Status Effect
+impl StatusEffect {
+    // Check whether the effect has worn off by the given time
+    pub fn expired(&self, now: &GameTime) -> bool {
+        (now.day, now.tick) >= (self.expires_day, self.expires_tick)
+    }
+
+    // Number of ticks left before the effect wears off
+    pub fn remaining_ticks(&self, now: &GameTime) -> u32 {
+        let expires = self.expires_day.saturating_sub(1) * crate::world::time::TICKS_PER_DAY
+            + self.expires_tick;
+        let current = now.day.saturating_sub(1) * crate::world::time::TICKS_PER_DAY + now.tick;
+
+        expires.saturating_sub(current)
+    }
+}
+
+// Struct for every status effect on the player
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct StatusEffects {
+    pub effects: Vec<StatusEffect>,
+}
+
+// Functions for Status Effects
+impl StatusEffects {
+    // Apply an effect for a number of ticks, an effect already in place lasts whichever is longer
+    pub fn apply(&mut self, kind: EffectKind, duration_ticks: u32, now: &GameTime) {
+        let mut expires = now.clone();
+        expires.advance(duration_ticks);
+
+        let effect = StatusEffect {
+            kind,
+            expires_day: expires.day,
+            expires_tick: expires.tick,
+        };
+
+        match self
+            .effects
+            .iter_mut()
+            .find(|existing| existing.kind == effect.kind)
+        {
+            Some(existing) => {
+                if (effect.expires_day, effect.expires_tick)
+                    > (existing.expires_day, existing.expires_tick)
+                {
+                    *existing = effect;
+                }
+            }
+            None => self.effects.push(effect),
+        }
+    }
+
+    // Remove any effects that have worn off
+    pub fn expire(&mut self, now: &GameTime) {
+        self.effects.retain(|effect| !effect.expired(now));
+    }
+
+    // Remove every harmful effect, returns how many were removed
+    pub fn remove_harmful(&mut self) -> usize {
+        let before = self.effects.len();
+        self.effects.retain(|effect| !effect.kind.harmful());
+
+        before - self.effects.len()
+    }
+
+    // Check whether an effect is in place
+    pub fn has(&self, kind: &EffectKind) -> bool {
+        self.effects.iter().any(|effect| &effect.kind == kind)
+    }
+
+    // Bonus to reputation when trading
+    pub fn reputation_bonus(&self) -> i32 {
+        if self.has(&EffectKind::Fortune) {
+            FORTUNE_REPUTATION
+        } else {
+            0
+        }
+    }
+
+    // Adjust a travel fare for any effects
+    pub fn adjust_fare(&self, fare: u32) -> u32 {
+        if self.has(&EffectKind::Wayfarer) {
+            (fare as f32 * WAYFARER_FARE_MODIFIER).round() as u32
+        } else {
+            fare
+        }
+    }
+
+    // Adjust a journey's length in ticks for any effects
+    pub fn adjust_travel_time(&self, ticks: u32) -> u32 {
+        if self.has(&EffectKind::Chilled) {
+            (ticks as f32 * CHILLED_TRAVEL_MODIFIER).round() as u32
+        } else {
+            ticks
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // A time late on the first day, so effects run over into the next
+    fn late_on_day_one() -> GameTime {
+        let mut now = GameTime::new();
+        now.advance(800);
+        now
+    }
+
+    fn later(now: &GameTime, ticks: u32) -> GameTime {
+        let mut time = now.clone();
+        time.advance(ticks);
+        time
+    }
+
+    #[test]
+    fn effects_wear_off_across_the_day_boundary() {
+        let now = late_on_day_one();
+        let mut effects = StatusEffects::default();
+        effects.apply(EffectKind::Wayfarer, 300, &now);
+
+        let effect = &effects.effects[0];
+        assert_eq!((effect.expires_day, effect.expires_tick), (2, 200));
+        assert_eq!(effect.remaining_ticks(&now), 300);
+
+        // Still going just after midnight and one tick before the end
+        let after_midnight = later(&now, 150);
+        assert_eq!(after_midnight.day, 2);
+        effects.expire(&after_midnight);
+        assert!(effects.has(&EffectKind::Wayfarer));
+        assert_eq!(effects.effects[0].remaining_ticks(&after_midnight), 150);
+
+        let last_tick = later(&now, 299);
+        effects.expire(&last_tick);
+        assert!(effects.has(&EffectKind::Wayfarer));
+        assert_eq!(effects.effects[0].remaining_ticks(&last_tick), 1);
+
+        let expiry = later(&now, 300);
+        assert!(effects.effects[0].expired(&expiry));
+        assert_eq!(effects.effects[0].remaining_ticks(&expiry), 0);
+        effects.expire(&expiry);
+        assert!(!effects.has(&EffectKind::Wayfarer));
+    }
+
+    #[test]
+    fn reapplying_an_effect_keeps_the_later_expiry() {
+        let now = late_on_day_one();
+        let mut effects = StatusEffects::default();
+        effects.apply(EffectKind::Fortune, 500, &now);
+
+        // A shorter dose doesn't cut the effect short
+        effects.apply(EffectKind::Fortune, 100, &later(&now, 50));
+        assert_eq!(effects.effects.len(), 1);
+        assert_eq!(effects.effects[0].remaining_ticks(&now), 500);
+
+        // A longer one extends it
+        let then = later(&now, 450);
+        effects.apply(EffectKind::Fortune, 900, &then);
+        assert_eq!(effects.effects.len(), 1);
+        assert_eq!(effects.effects[0].remaining_ticks(&then), 900);
+        assert_eq!(
+            (
+                effects.effects[0].expires_day,
+                effects.effects[0].expires_tick
+            ),
+            (3, 350)
+        );
+    }
+
+    #[test]
+    fn healing_removes_only_harmful_effects() {
+        let now = GameTime::new();
+        let mut effects = StatusEffects::default();
+        effects.apply(EffectKind::Chilled, 100, &now);
+        effects.apply(EffectKind::Fortune, 100, &now);
+
+        assert_eq!(effects.adjust_travel_time(100), 125);
+        assert_eq!(effects.remove_harmful(), 1);
+        assert!(!effects.has(&EffectKind::Chilled));
+        assert!(effects.has(&EffectKind::Fortune));
+        assert_eq!(effects.adjust_travel_time(100), 100);
+
+        assert_eq!(effects.remove_harmful(), 0);
+    }
+
+    #[test]
+    fn remaining_ticks_never_goes_below_zero() {
+        let now = GameTime::new();
+        let mut effects = StatusEffects::default();
+        effects.apply(EffectKind::Chilled, 10, &now);
+
+        assert_eq!(effects.effects[0].remaining_ticks(&later(&now, 2000)), 0);
+    }
+}
@@ -0,0 +1,238 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+// Consts for default inventory capacity
+const DEFAULT_MAX_SLOTS: usize = 20;
+const DEFAULT_MAX_WEIGHT: f32 = 50.0;
+
+// Struct for an inventory, a list of item stacks with slot and weight limits
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Inventory {
+    pub stacks: Vec<ItemStack>,
+    pub max_slots: usize,
+    pub max_weight: f32,
+}
+
+// Functions for Inventory
+impl Inventory {
+    // Create a new empty Inventory with default capacity
+    pub fn new() -> Self {
+        Self {
+            stacks: Vec::new(),
+            max_slots: DEFAULT_MAX_SLOTS,
+            max_weight: DEFAULT_MAX_WEIGHT,
+        }
+    }
+
+    // Total weight of everything carried
+    pub fn total_weight(&self) -> f32 {
+        self.stacks
+            .iter()
+            .map(|stack| stack.item.weight * stack.quantity as f32)
+            .sum()
+    }
+
+    // Check whether a quantity of an item would fit, without adding it
+    pub fn can_add(&self, item: &super::item::Item, quantity: u32) -> Result<(), InventoryError> {
+        if self.total_weight() + item.weight * quantity as f32 > self.max_weight {
+            return Err(InventoryError::TooHeavy);
+        }
+
+        // Room left in existing stacks of the same item
+        let stack_room: u32 = self
+            .stacks
+            .iter()
+            .filter(|stack| stack.item.id == item.id)
+            .map(|stack| item.max_stack.saturating_sub(stack.quantity))
+            .sum();
+
+        let overflow = quantity.saturating_sub(stack_room);
+        let slots_needed = overflow.div_ceil(item.max_stack.max(1)) as usize;
+
+        if self.stacks.len() + slots_needed > self.max_slots {
+            return Err(InventoryError::NoSpace);
+        }
+
+        Ok(())
+    }
+
+    // Add a quantity of an item, topping up existing stacks before using new slots
+    pub fn add(&mut self, item: super::item::Item, quantity: u32) -> Result<(), InventoryError> {
+        self.can_add(&item, quantity)?;
+
+        let mut remaining = quantity;
+
+        for stack in self
+            .stacks
+            .iter_mut()
+            .filter(|stack| stack.item.id == item.id)
+        {
+            let added = remaining.min(item.max_stack.saturating_sub(stack.quantity));
+            stack.quantity += added;
+            remaining -= added;
+        }
+
+        while remaining > 0 {
+            let added = remaining.min(item.max_stack.max(1));
+            self.stacks.push(ItemStack {
+                item: item.clone(),
+                quantity: added,
+            });
+            remaining -= added;
+        }
+
+        Ok(())
+    }
+
+    // Remove up to a quantity from the stack at index, returns what was removed
+    pub fn remove(&mut self, index: usize, quantity: u32) -> Option<ItemStack> {
+        let stack = self.stacks.get_mut(index)?;
+        let removed = quantity.min(stack.quantity);
+
+        stack.quantity -= removed;
+        let item = stack.item.clone();
+
+        if stack.quantity == 0 {
+            self.stacks.remove(index);
+        }
+
+        Some(ItemStack {
+            item,
+            quantity: removed,
+        })
+    }
+}
+
+impl Default for Inventory {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// Struct for a stack of identical items
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ItemStack {
+    pub item: super::item::Item,
+    pub quantity: u32,
+}
+
+// Enum for reasons an item can't be added to an inventory
+#[derive(Debug)]
+pub enum InventoryError {
+    TooHeavy,
+    NoSpace,
+}
+
+impl fmt::Display for InventoryError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            InventoryError::TooHeavy => write!(f, "You can't carry that much weight."),
+            InventoryError::NoSpace => write!(f, "Your pack has no room left."),
+        }
+    }
+}
+
+impl std::error::Error for InventoryError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entities::item::Item;
+
+    fn item(id: u32) -> Item {
+        Item::from_id(id).unwrap()
+    }
+
+    fn quantities(inventory: &Inventory) -> Vec<(u32, u32)> {
+        inventory
+            .stacks
+            .iter()
+            .map(|stack| (stack.item.id, stack.quantity))
+            .collect()
+    }
+
+    #[test]
+    fn tops_up_stacks_to_their_maximum_before_starting_new_ones() {
+        let bread = item(1);
+        assert_eq!(bread.max_stack, 10);
+
+        let mut inventory = Inventory::new();
+        inventory.add(bread.clone(), 7).unwrap();
+        inventory.add(bread.clone(), 7).unwrap();
+        assert_eq!(quantities(&inventory), [(1, 10), (1, 4)]);
+
+        inventory.add(bread.clone(), 16).unwrap();
+        assert_eq!(quantities(&inventory), [(1, 10), (1, 10), (1, 10)]);
+
+        // Items that don't stack take a slot each
+        inventory.add(item(7), 2).unwrap();
+        assert_eq!(quantities(&inventory)[3..], [(7, 1), (7, 1)]);
+    }
+
+    #[test]
+    fn refuses_items_once_the_slots_are_full() {
+        let mut inventory = Inventory::new();
+        inventory.max_slots = 2;
+
+        inventory.add(item(1), 4).unwrap();
+        inventory.add(item(7), 1).unwrap();
+
+        assert!(matches!(
+            inventory.can_add(&item(7), 1),
+            Err(InventoryError::NoSpace)
+        ));
+
+        // Room left in a stack still counts
+        inventory.add(item(1), 6).unwrap();
+        assert!(matches!(
+            inventory.add(item(1), 1),
+            Err(InventoryError::NoSpace)
+        ));
+        assert_eq!(quantities(&inventory), [(1, 10), (7, 1)]);
+    }
+
+    #[test]
+    fn refuses_items_past_the_weight_limit() {
+        let jerkin = item(31);
+        let mut inventory = Inventory::new();
+
+        inventory.add(jerkin.clone(), 12).unwrap();
+        assert_eq!(inventory.total_weight(), 48.0);
+
+        // Right up to the limit is fine
+        inventory.add(item(1), 4).unwrap();
+        assert_eq!(inventory.total_weight(), inventory.max_weight);
+
+        assert!(matches!(
+            inventory.add(item(1), 1),
+            Err(InventoryError::TooHeavy)
+        ));
+        assert!(matches!(
+            inventory.can_add(&jerkin, 1),
+            Err(InventoryError::TooHeavy)
+        ));
+        assert_eq!(inventory.stacks.len(), 13);
+        assert_eq!(
+            InventoryError::TooHeavy.to_string(),
+            "You can't carry that much weight."
+        );
+    }
+
+    #[test]
+    fn removes_part_or_all_of_a_stack() {
+        let mut inventory = Inventory::new();
+        inventory.add(item(1), 10).unwrap();
+        inventory.add(item(3), 5).unwrap();
+
+        let removed = inventory.remove(0, 3).unwrap();
+        assert_eq!((removed.item.id, removed.quantity), (1, 3));
+        assert_eq!(quantities(&inventory), [(1, 7), (3, 5)]);
+
+        // Asking for more than there is takes the whole stack and frees the slot
+        let removed = inventory.remove(0, 20).unwrap();
+        assert_eq!((removed.item.id, removed.quantity), (1, 7));
+        assert_eq!(quantities(&inventory), [(3, 5)]);
+
+        assert!(inventory.remove(1, 1).is_none());
+    }
+}
@@ -0,0 +1,71 @@
+use serde::{Deserialize, Serialize};
+
+// Struct for an item
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Item {
+    pub id: u32,
+    pub name: String,
+    pub kind: ItemKind,
+    pub weight: f32,
+    pub value: u32,
+    pub max_stack: u32,
+}
+
+// Functions for Item
+impl Item {
+    // Create a new Item
+    fn new(id: u32, name: &str, kind: ItemKind, weight: f32, value: u32, max_stack: u32) -> Self {
+        Self {
+            id,
+            name: name.into(),
+            kind,
+            weight,
+            value,
+            max_stack,
+        }
+    }
+
+    // Look up an item from the catalogue by its id
+    pub fn from_id(id: u32) -> Option<Self> {
+        catalogue().into_iter().find(|item| item.id == id)
+    }
+}
+
+// Enum for item kinds
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum ItemKind {
+    Weapon,
+    Armour,
+    Food,
+    Potion,
+    Material,
+    Treasure,
+    Misc,
+}
+
+// Every item that exists in the game
+pub fn catalogue() -> Vec<Item> {
+    vec![
+        Item::new(1, "Bread", ItemKind::Food, 0.5, 2, 10),
+        Item::new(2, "Cheese", ItemKind::Food, 0.5, 4, 10),
+        Item::new(3, "Apple", ItemKind::Food, 0.2, 1, 20),
+        Item::new(4, "Ale", ItemKind::Food, 1.0, 3, 5),
+        Item::new(5, "Waterskin", ItemKind::Misc, 1.0, 5, 1),
+        Item::new(6, "Torch", ItemKind::Misc, 1.0, 2, 5),
+        Item::new(7, "Rope", ItemKind::Misc, 2.0, 6, 1),
+        Item::new(8, "Candle", ItemKind::Misc, 0.1, 1, 20),
+        Item::new(20, "Dagger", ItemKind::Weapon, 1.0, 15, 1),
+        Item::new(21, "Shortsword", ItemKind::Weapon, 3.0, 40, 1),
+        Item::new(22, "Hand Axe", ItemKind::Weapon, 2.5, 30, 1),
+        Item::new(30, "Leather Cap", ItemKind::Armour, 1.0, 12, 1),
+        Item::new(31, "Padded Jerkin", ItemKind::Armour, 4.0, 35, 1),
+        Item::new(40, "Healing Draught", ItemKind::Potion, 0.5, 25, 5),
+        Item::new(41, "Tonic of Vigour", ItemKind::Potion, 0.5, 30, 5),
+        Item::new(50, "Cloth", ItemKind::Material, 0.5, 3, 20),
+        Item::new(51, "Iron Nails", ItemKind::Material, 0.2, 1, 50),
+        Item::new(52, "Herbs", ItemKind::Material, 0.1, 2, 20),
+        Item::new(60, "Silver Ring", ItemKind::Treasure, 0.1, 50, 1),
+        Item::new(61, "Gemstone", ItemKind::Treasure, 0.1, 80, 10),
+        Item::new(62, "Old Coin", ItemKind::Treasure, 0.1, 10, 20),
+    ]
+}
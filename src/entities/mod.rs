@@ -1 +1,5 @@
+pub mod character;
+pub mod effects;
+pub mod inventory;
+pub mod item;
 pub mod player;
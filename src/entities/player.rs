@@ -1,4 +1,11 @@
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+// Consts for what a new player starts with, (item id, quantity)
+const STARTING_ITEMS: [(u32, u32); 4] = [(1, 3), (5, 1), (6, 2), (20, 1)];
+
+// Const for default starting gold, also used for saves from before gold existed
+pub const STARTING_GOLD: u32 = 500;
 
 // Struct for player
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -6,16 +13,73 @@ pub struct Player {
     pub id: u32,
     pub name: String,
     pub town_name: String,
+    #[serde(default = "starting_gold")]
+    pub gold: u32,
+    #[serde(default)]
+    pub inventory: super::inventory::Inventory,
+    #[serde(default)]
+    pub reputation: HashMap<u32, i32>,
+    #[serde(default)]
+    pub effects: super::effects::StatusEffects,
+    #[serde(default)]
+    pub character: super::character::Character,
+    #[serde(default)]
+    pub position: (u32, u32),
 }
 
 // Functions for player
 impl Player {
     // Create a new player
-    pub fn new(id: u32, name: String, town_name: String) -> Self {
+    pub fn new(
+        id: u32,
+        name: String,
+        town_name: String,
+        gold: u32,
+        character: super::character::Character,
+    ) -> Self {
         Self {
             id,
             name,
             town_name,
+            gold,
+            inventory: starting_inventory(),
+            reputation: HashMap::new(),
+            effects: super::effects::StatusEffects::default(),
+            character,
+            position: (0, 0),
+        }
+    }
+
+    // Get the player's reputation in a town
+    pub fn reputation_in(&self, town_id: u32) -> i32 {
+        self.reputation.get(&town_id).copied().unwrap_or(0)
+    }
+
+    // Get the reputation shops in a town treat the player as having, including any blessings
+    pub fn trading_reputation(&self, town_id: u32) -> i32 {
+        self.reputation_in(town_id) + self.effects.reputation_bonus()
+    }
+}
+
+// Default gold for saves from before gold existed
+fn starting_gold() -> u32 {
+    STARTING_GOLD
+}
+
+// Build the inventory a new player starts with
+fn starting_inventory() -> super::inventory::Inventory {
+    let mut inventory = super::inventory::Inventory::new();
+
+    for (item_id, quantity) in STARTING_ITEMS {
+        match super::item::Item::from_id(item_id) {
+            Some(item) => {
+                if let Err(e) = inventory.add(item, quantity) {
+                    log::error!("Failed to add starting item {}: {}", item_id, e);
+                }
+            }
+            None => log::error!("Failed to find starting item: {}", item_id),
         }
     }
+
+    inventory
 }
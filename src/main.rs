@@ -2,8 +2,10 @@ use env_logger::Builder;
 use log::LevelFilter;
 use std::fs::File;
 use std::io::Write;
+use std::path::PathBuf;
 use std::process;
 
+mod ai;
 mod core;
 mod entities;
 mod ui;
@@ -11,11 +13,21 @@ mod world;
 
 // Main function
 fn main() {
+    // Read command-line arguments
+    let world_dir = match parse_args() {
+        Ok(world_dir) => world_dir,
+        Err(e) => {
+            eprintln!("{}", e);
+            eprintln!("Usage: cli-town-explorer [--world <dir>]");
+            process::exit(2);
+        }
+    };
+
     // Initialise logging
     match init_logger() {
         Ok(()) => {
             // Starts Ratatui and in turn the main loop
-            match ui::display::start() {
+            match ui::display::start(world_dir) {
                 Ok(()) => {
                     process::exit(0);
                 }
@@ -33,6 +45,32 @@ fn main() {
     }
 }
 
+// Parse command-line arguments, returns the world directory if one was given with --world
+fn parse_args() -> Result<Option<PathBuf>, String> {
+    let mut args = std::env::args().skip(1);
+    let mut world_dir = None;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--world" => {
+                let dir = PathBuf::from(args.next().ok_or("Missing directory after --world")?);
+
+                if !dir.join("world.json").is_file() || !dir.join("world.dot").is_file() {
+                    return Err(format!(
+                        "No world.json and world.dot found in {}",
+                        dir.display()
+                    ));
+                }
+
+                world_dir = Some(dir);
+            }
+            _ => return Err(format!("Unknown argument: {}", arg)),
+        }
+    }
+
+    Ok(world_dir)
+}
+
 fn init_logger() -> Result<(), std::io::Error> {
     let log_file = File::create("output.log")?;
 
@@ -1,16 +1,19 @@
 use color_eyre::Result;
 use ratatui::layout::{Alignment, Constraint, Direction, Layout};
 use ratatui::prelude::Rect;
-use ratatui::widgets::{Block, BorderType, Borders, Clear, List, Paragraph};
+use ratatui::widgets::{Block, BorderType, Borders, Clear, List, Paragraph, Wrap};
 use ratatui::{DefaultTerminal, Frame};
 
 // Struct for Managers
 pub struct Managers {
+    pub config: crate::core::config::Config,
     pub state_manager: crate::core::states::StateManager,
     pub world_manager: crate::world::manager::WorldManager,
     pub time_manager: crate::world::time::TimeManager,
     pub weather_manager: crate::world::weather::WeatherManager,
     pub save_manager: crate::core::save::SaveGameManager,
+    pub dialogue_manager: crate::ai::dialogue::DialogueManager,
+    pub narrator_manager: crate::ai::narrator::NarratorManager,
 }
 
 // Struct for UI Components
@@ -18,41 +21,71 @@ pub struct UIComponents {
     pub menu: super::menu::Menu,
     pub viewport: super::viewport::Viewport,
     stats: super::stats::Stats,
+    map: super::map::Map,
     pub popup: super::popup::Popup,
 }
 
 // Starts Ratatui and launches the main loop with run()
 // Restores original terminal when main loop in run() finishes
-pub fn start() -> Result<()> {
+pub fn start(world_dir: Option<std::path::PathBuf>) -> Result<()> {
     color_eyre::install()?;
     let terminal = ratatui::init();
 
-    let result = run(terminal);
+    let result = run(terminal, world_dir);
 
     ratatui::restore();
     result
 }
 
 // Main loop
-fn run(mut terminal: DefaultTerminal) -> Result<()> {
+fn run(mut terminal: DefaultTerminal, world_dir: Option<std::path::PathBuf>) -> Result<()> {
+    let config = crate::core::config::Config::load();
+
     let mut managers = Managers {
         state_manager: crate::core::states::StateManager::new(),
         world_manager: crate::world::manager::WorldManager::new(),
         time_manager: crate::world::time::TimeManager::new(),
         weather_manager: crate::world::weather::WeatherManager::new(),
-        save_manager: crate::core::save::SaveGameManager::new(),
+        save_manager: crate::core::save::SaveGameManager::new(&config),
+        dialogue_manager: crate::ai::dialogue::DialogueManager::new(&config),
+        narrator_manager: crate::ai::narrator::NarratorManager::new(&config),
+        config,
     };
 
+    // Find the worlds to choose from, unless one was given on the command line
+    match world_dir {
+        Some(world_dir) => managers.world_manager.choose_world(world_dir),
+        None => managers.world_manager.scan_worlds(),
+    }
+
     let mut ui_components = UIComponents {
         menu: super::menu::Menu::new(),
         viewport: super::viewport::Viewport::new(),
         stats: super::stats::Stats::new(),
+        map: super::map::Map::new(),
         popup: super::popup::Popup::new(),
     };
 
     loop {
         // Update
+        managers.dialogue_manager.update();
+        if let (Some(player), Some(time)) = (
+            managers.world_manager.player.as_mut(),
+            managers.time_manager.current(),
+        ) {
+            player.effects.expire(&time);
+        }
+        if managers
+            .save_manager
+            .autosave_due(managers.config.autosave_interval_secs)
+        {
+            crate::core::events::autosave(&mut managers);
+        }
+        crate::core::events::update_journey(&mut managers, &mut ui_components);
+        let scene = crate::ai::narrator::Scene::current(&managers);
+        managers.narrator_manager.update(scene);
         ui_components.menu.update(&managers);
+        ui_components.viewport.highlighted = ui_components.menu.selected_index;
         ui_components.viewport.update(&managers);
         ui_components.popup.update(&managers);
 
@@ -96,12 +129,35 @@ fn render(frame: &mut Frame, managers: &Managers, ui_components: &mut UIComponen
         List::new(menu_options).block(Block::default().title("Menu").borders(Borders::ALL));
     frame.render_widget(menu_block, horizontal[1]);
 
-    // Viewport
+    // Viewport, sharing its space with the map on the Map screen
+    let viewport_area = match managers.state_manager.current_state {
+        crate::core::states::StateType::Map => {
+            let map_split = Layout::default()
+                .direction(Direction::Horizontal)
+                .constraints([
+                    Constraint::Percentage(75), // Map
+                    Constraint::Percentage(25), // Viewport
+                ])
+                .split(horizontal[0]);
+
+            ui_components.map.render(
+                frame,
+                map_split[0],
+                managers,
+                ui_components.viewport.highlighted,
+            );
+
+            map_split[1]
+        }
+        _ => horizontal[0],
+    };
+
     let viewport_text = ui_components.viewport.render(managers);
 
     let viewport_block = Paragraph::new(viewport_text)
-        .block(Block::default().title("Viewport").borders(Borders::ALL));
-    frame.render_widget(viewport_block, horizontal[0]);
+        .block(Block::default().title("Viewport").borders(Borders::ALL))
+        .wrap(Wrap { trim: false });
+    frame.render_widget(viewport_block, viewport_area);
 
     // Stats
     let stats_text = ui_components.stats.render(managers);
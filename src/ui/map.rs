@@ -0,0 +1,138 @@
+use ratatui::layout::Rect;
+use ratatui::style::{Color, Stylize};
+use ratatui::symbols::Marker;
+use ratatui::widgets::canvas::{Canvas, Line as CanvasLine};
+use ratatui::widgets::{Block, Borders};
+use ratatui::Frame;
+
+// Struct for Map
+pub struct Map {}
+
+// Functions for Map
+impl Map {
+    // Create a new Map
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    // Draws the world map, the roads of the planned route in yellow and the player's town in green
+    pub fn render(
+        &self,
+        frame: &mut Frame,
+        area: Rect,
+        managers: &super::display::Managers,
+        highlighted: usize,
+    ) {
+        let block = Block::default().title("Map").borders(Borders::ALL);
+
+        let Some(world_map) = managers.world_manager.map.as_ref() else {
+            frame.render_widget(block, area);
+            return;
+        };
+
+        let current_town = managers
+            .world_manager
+            .player
+            .as_ref()
+            .map(|player| player.town_name.clone())
+            .unwrap_or_default();
+        let route = planned_route(managers, highlighted);
+        let on_route = |from: &str, to: &str| {
+            route
+                .iter()
+                .any(|(a, b)| (a == from && b == to) || (a == to && b == from))
+        };
+
+        let (x_bounds, y_bounds) = world_map.bounds();
+
+        let canvas = Canvas::default()
+            .block(block)
+            .marker(Marker::Braille)
+            .x_bounds(x_bounds)
+            .y_bounds(y_bounds)
+            .paint(|ctx| {
+                // Roads, the planned route drawn last so it sits on top
+                let mut roads: Vec<&crate::world::map::Road> = world_map.roads.iter().collect();
+                roads.sort_by_key(|road| on_route(&road.from, &road.to));
+
+                for road in &roads {
+                    if let (Some(&(x1, y1)), Some(&(x2, y2))) = (
+                        world_map.positions.get(&road.from),
+                        world_map.positions.get(&road.to),
+                    ) {
+                        let color = if on_route(&road.from, &road.to) {
+                            Color::Yellow
+                        } else {
+                            Color::DarkGray
+                        };
+
+                        ctx.draw(&CanvasLine::new(x1, y1, x2, y2, color));
+                    }
+                }
+
+                ctx.layer();
+
+                // Miles along each road, halfway between the towns
+                for road in &roads {
+                    if let (Some(&(x1, y1)), Some(&(x2, y2))) = (
+                        world_map.positions.get(&road.from),
+                        world_map.positions.get(&road.to),
+                    ) {
+                        ctx.print(
+                            (x1 + x2) / 2.0,
+                            (y1 + y2) / 2.0,
+                            format!("{}mi", road.distance).dark_gray(),
+                        );
+                    }
+                }
+
+                // Towns
+                for (name, &(x, y)) in &world_map.positions {
+                    if *name == current_town {
+                        ctx.print(x, y, format!("@ {}", name).green().bold());
+                    } else if route.iter().any(|(_, to)| to == name) {
+                        ctx.print(x, y, format!("* {}", name).yellow());
+                    } else {
+                        ctx.print(x, y, format!("* {}", name));
+                    }
+                }
+            });
+
+        frame.render_widget(canvas, area);
+    }
+}
+
+// The roads still to travel, the rest of the journey if on the road or the route to the highlighted destination
+fn planned_route(managers: &super::display::Managers, highlighted: usize) -> Vec<(String, String)> {
+    if let Some(journey) = managers.world_manager.journey.as_ref() {
+        return journey
+            .legs
+            .iter()
+            .skip(journey.leg)
+            .map(|leg| (leg.from.clone(), leg.to.clone()))
+            .collect();
+    }
+
+    let Some(player) = managers.world_manager.player.as_ref() else {
+        return Vec::new();
+    };
+
+    managers
+        .world_manager
+        .travel_destinations()
+        .get(highlighted)
+        .and_then(|destination| {
+            managers
+                .world_manager
+                .plan_route(&player.town_name, destination)
+                .ok()
+        })
+        .map(|route| {
+            route
+                .legs
+                .iter()
+                .map(|leg| (leg.from.clone(), leg.to.clone()))
+                .collect()
+        })
+        .unwrap_or_default()
+}
@@ -1,12 +1,34 @@
 use ratatui::style::{Color, Modifier, Style};
 use ratatui::widgets::ListItem;
+use strum::IntoEnumIterator;
 
 // Consts for menu options
 const OPTIONS_MAIN_MENU: [&str; 3] = ["New Game", "Load Game", "Exit"];
 const OPTIONS_CONFIRM: [&str; 2] = ["Enter to Confirm", "Esc to Cancel"];
 const OPTIONS_CONTINUE: [&str; 1] = ["Enter to Continue"];
-const OPTIONS_GAME: [&str; 5] = ["Time", "Weather", "Travel", "Save", "Quit"];
+const OPTIONS_GAME: [&str; 8] = [
+    "Time",
+    "Weather",
+    "Travel",
+    "Map",
+    "Explore",
+    "Inventory",
+    "Save",
+    "Quit",
+];
+const OPTIONS_BACK: [&str; 1] = ["Back"];
+const OPTIONS_SHOP: [&str; 4] = ["Buy", "Sell", "Look Around", "Leave"];
+const OPTIONS_TAVERN: [&str; 3] = ["Hear Rumours", "Look Around", "Leave"];
+const OPTIONS_TEMPLE: [&str; 2] = ["Look Around", "Leave"];
+const OPTIONS_INVENTORY_ITEM: [&str; 3] = ["Drop One", "Drop All", "Back"];
+const OPTIONS_CONVERSATION: [&str; 2] = ["Enter to Send", "Esc to Leave (or stop a reply)"];
+const OPTIONS_TOWN_MAP: [&str; 3] = ["Arrows to Move", "Enter to Go Inside", "Esc to Leave"];
 const OPTIONS_GAME_QUIT: [&str; 2] = ["Yes", "No"];
+const OPTIONS_LOAD_SLOT: [&str; 4] = ["Load", "Rename", "Delete", "Back"];
+const OPTIONS_CHECKSUM_MISMATCH: [&str; 3] = ["Load Anyway", "Restore Backup", "Cancel"];
+const OPTIONS_WORLD_MISMATCH: [&str; 2] = ["Load Anyway", "Cancel"];
+const OPTIONS_CREATE_METHOD: [&str; 3] = ["Point Buy", "Roll Dice", "Back"];
+const OPTIONS_POINT_BUY: [&str; 2] = ["Done", "Back"];
 
 // Struct for Menu
 pub struct Menu {
@@ -48,11 +70,143 @@ impl Menu {
                 self.menu_options
                     .extend(OPTIONS_MAIN_MENU.iter().map(|&option| option.to_string()));
             }
-            // New Game
-            crate::core::states::StateType::Name | crate::core::states::StateType::NameConfirm => {
+            // New Game and Load Game (Rename Slot)
+            crate::core::states::StateType::Name
+            | crate::core::states::StateType::NameConfirm
+            | crate::core::states::StateType::LoadSlotRename => {
                 self.menu_options
                     .extend(OPTIONS_CONFIRM.iter().map(|&option| option.to_string()));
             }
+            // New Game (choose race)
+            crate::core::states::StateType::CreateRace => {
+                self.menu_options.extend(
+                    crate::world::manager::NpcRace::iter().map(|race| format!("{:?}", race)),
+                );
+                self.menu_options
+                    .extend(OPTIONS_BACK.iter().map(|&option| option.to_string()));
+            }
+            // New Game (choose sex)
+            crate::core::states::StateType::CreateSex => {
+                self.menu_options
+                    .extend(crate::world::manager::NpcSex::iter().map(|sex| format!("{:?}", sex)));
+                self.menu_options
+                    .extend(OPTIONS_BACK.iter().map(|&option| option.to_string()));
+            }
+            // New Game (choose background)
+            crate::core::states::StateType::CreateBackground => {
+                self.menu_options.extend(
+                    crate::entities::character::Background::iter().map(|background| {
+                        format!("{:?} (+1 {:?})", background, background.bonus())
+                    }),
+                );
+                self.menu_options
+                    .extend(OPTIONS_BACK.iter().map(|&option| option.to_string()));
+            }
+            // New Game (choose how to set attributes)
+            crate::core::states::StateType::CreateMethod => {
+                self.menu_options.extend(
+                    OPTIONS_CREATE_METHOD
+                        .iter()
+                        .map(|&option| option.to_string()),
+                );
+            }
+            // New Game (point buy)
+            crate::core::states::StateType::CreatePointBuy => {
+                let creation = &managers.world_manager.character_creation;
+
+                self.menu_options
+                    .extend(
+                        crate::entities::character::Attribute::iter().map(|attribute| {
+                            format!(
+                                "{:?}: < {} >",
+                                attribute,
+                                creation.attributes.get(attribute)
+                            )
+                        }),
+                    );
+                self.menu_options
+                    .extend(OPTIONS_POINT_BUY.iter().map(|&option| option.to_string()));
+            }
+            // New Game (roll dice)
+            crate::core::states::StateType::CreateRoll => {
+                self.menu_options.push("Accept".to_string());
+                self.menu_options.push(format!(
+                    "Reroll ({} left)",
+                    managers.world_manager.character_creation.rerolls_left
+                ));
+                self.menu_options
+                    .extend(OPTIONS_BACK.iter().map(|&option| option.to_string()));
+            }
+            // New Game (select world)
+            crate::core::states::StateType::WorldSelect => {
+                self.menu_options
+                    .extend(
+                        managers
+                            .world_manager
+                            .available_worlds
+                            .iter()
+                            .map(|world_info| {
+                                format!(
+                                    "{} ({} towns, {} buildings, {} NPCs)",
+                                    world_info.name,
+                                    world_info.towns,
+                                    world_info.buildings,
+                                    world_info.npcs
+                                )
+                            }),
+                    );
+                self.menu_options
+                    .extend(OPTIONS_BACK.iter().map(|&option| option.to_string()));
+            }
+            // New Game (select starting town)
+            crate::core::states::StateType::TownSelect => {
+                self.menu_options.push("Random Town".to_string());
+                self.menu_options
+                    .extend(managers.world_manager.town_names());
+                self.menu_options
+                    .extend(OPTIONS_BACK.iter().map(|&option| option.to_string()));
+            }
+            // Load Game (list save slots)
+            crate::core::states::StateType::LoadGame => {
+                self.menu_options.extend(
+                    managers
+                        .save_manager
+                        .slots
+                        .iter()
+                        .map(|slot| slot.name.clone()),
+                );
+                self.menu_options
+                    .extend(OPTIONS_BACK.iter().map(|&option| option.to_string()));
+            }
+            // Load Game (selected slot)
+            crate::core::states::StateType::LoadSlot => {
+                self.menu_options
+                    .extend(OPTIONS_LOAD_SLOT.iter().map(|&option| option.to_string()));
+            }
+            // Load Game (world mismatch)
+            crate::core::states::StateType::LoadWorldMismatch => {
+                if managers
+                    .save_manager
+                    .world_mismatch
+                    .as_ref()
+                    .is_some_and(|mismatch| mismatch.matching_available)
+                {
+                    self.menu_options.push("Load Matching World".to_string());
+                }
+                self.menu_options.extend(
+                    OPTIONS_WORLD_MISMATCH
+                        .iter()
+                        .map(|&option| option.to_string()),
+                );
+            }
+            // Load Game (checksum mismatch)
+            crate::core::states::StateType::LoadChecksumMismatch => {
+                self.menu_options.extend(
+                    OPTIONS_CHECKSUM_MISMATCH
+                        .iter()
+                        .map(|&option| option.to_string()),
+                );
+            }
             // Game, Time, and Weather
             crate::core::states::StateType::Game
             | crate::core::states::StateType::Time
@@ -62,12 +216,237 @@ impl Menu {
             }
             // Travel
             crate::core::states::StateType::Travel => {
-                if let Some(world) = managers.world_manager.world.as_ref() {
+                if let Some(player) = managers.world_manager.player.as_ref() {
+                    self.menu_options.extend(
+                        managers
+                            .world_manager
+                            .travel_destinations()
+                            .iter()
+                            .map(|destination| {
+                                match managers
+                                    .world_manager
+                                    .plan_route(&player.town_name, destination)
+                                {
+                                    Ok(route) => format!(
+                                        "{} ({} miles, {} gold)",
+                                        destination,
+                                        route.distance(),
+                                        route.fare(&player.effects)
+                                    ),
+                                    Err(_) => format!("{} (unreachable)", destination),
+                                }
+                            }),
+                    );
+                }
+                self.menu_options
+                    .extend(OPTIONS_BACK.iter().map(|&option| option.to_string()));
+            }
+            // Journey
+            crate::core::states::StateType::Journey => {
+                let stopping = managers
+                    .world_manager
+                    .journey
+                    .as_ref()
+                    .is_some_and(|journey| journey.stop_early);
+
+                if stopping {
+                    self.menu_options.push("Keep Going".to_string());
+                } else {
+                    self.menu_options.push("Stop at Next Town".to_string());
+                }
+                self.menu_options.push("Save".to_string());
+                self.menu_options.push("Map".to_string());
+            }
+            // Map (destinations to plan a route to, only Back while on the road)
+            crate::core::states::StateType::Map => {
+                if managers.world_manager.journey.is_none() {
                     self.menu_options
-                        .extend(world.towns.values().map(|town| town.name.clone()));
-                    self.menu_options.push("Back".into());
+                        .extend(managers.world_manager.travel_destinations());
+                }
+                self.menu_options
+                    .extend(OPTIONS_BACK.iter().map(|&option| option.to_string()));
+            }
+            // Journey (encounter on the road)
+            crate::core::states::StateType::JourneyEncounter => {
+                if let Some(encounter) = managers
+                    .world_manager
+                    .journey
+                    .as_ref()
+                    .and_then(|journey| journey.encounter.as_ref())
+                {
+                    self.menu_options.extend(encounter.options());
                 }
             }
+            // Explore (list buildings in current town)
+            crate::core::states::StateType::Explore => {
+                if let Some(town) = managers.world_manager.current_town() {
+                    self.menu_options.extend(
+                        town.buildings()
+                            .iter()
+                            .map(|building| building.name().to_string()),
+                    );
+                }
+                self.menu_options.push("Town Map".to_string());
+                self.menu_options
+                    .extend(OPTIONS_BACK.iter().map(|&option| option.to_string()));
+            }
+            // Town Map
+            crate::core::states::StateType::TownMap => {
+                self.menu_options
+                    .extend(OPTIONS_TOWN_MAP.iter().map(|&option| option.to_string()));
+            }
+            // Building (list rooms in current building)
+            crate::core::states::StateType::Building => {
+                if let Some(building) = managers.world_manager.current_building() {
+                    self.menu_options.extend(
+                        (1..=building.rooms().len()).map(|number| format!("Room {}", number)),
+                    );
+                }
+                self.menu_options
+                    .extend(OPTIONS_BACK.iter().map(|&option| option.to_string()));
+            }
+            // Room (list NPCs to talk to and containers to search)
+            crate::core::states::StateType::Room => {
+                if let Some(room) = managers.world_manager.current_room() {
+                    self.menu_options.extend(
+                        room.npcs()
+                            .iter()
+                            .map(|npc| format!("Talk to {}", npc.name())),
+                    );
+                    self.menu_options.extend(
+                        room.containers()
+                            .iter()
+                            .map(|container| format!("Search {:?}", container.container_type())),
+                    );
+                }
+                self.menu_options
+                    .extend(OPTIONS_BACK.iter().map(|&option| option.to_string()));
+            }
+            // Container (list items to take)
+            crate::core::states::StateType::Container => {
+                if let Some(contents) =
+                    managers
+                        .world_manager
+                        .current_container_id
+                        .and_then(|container_id| {
+                            managers.world_manager.container_contents.get(&container_id)
+                        })
+                {
+                    self.menu_options.extend(
+                        contents
+                            .iter()
+                            .map(|stack| format!("Take {} x{}", stack.item.name, stack.quantity)),
+                    );
+                    if !contents.is_empty() {
+                        self.menu_options.push("Take All".into());
+                    }
+                }
+                self.menu_options
+                    .extend(OPTIONS_BACK.iter().map(|&option| option.to_string()));
+            }
+            // Shop
+            crate::core::states::StateType::Shop => {
+                self.menu_options
+                    .extend(OPTIONS_SHOP.iter().map(|&option| option.to_string()));
+            }
+            // Tavern
+            crate::core::states::StateType::Tavern => {
+                self.menu_options.push(format!(
+                    "Rest until Dawn ({} gold)",
+                    crate::world::tavern::REST_COST
+                ));
+                self.menu_options
+                    .extend(OPTIONS_TAVERN.iter().map(|&option| option.to_string()));
+            }
+            // Temple (list services)
+            crate::core::states::StateType::Temple => {
+                self.menu_options.extend(
+                    crate::world::temple::SERVICES
+                        .iter()
+                        .map(|service| format!("{} ({} gold)", service.name, service.cost)),
+                );
+                self.menu_options
+                    .extend(OPTIONS_TEMPLE.iter().map(|&option| option.to_string()));
+            }
+            // Tavern (rumours)
+            crate::core::states::StateType::TavernRumours => {
+                self.menu_options
+                    .extend(OPTIONS_BACK.iter().map(|&option| option.to_string()));
+            }
+            // Shop (list stock with buy prices)
+            crate::core::states::StateType::ShopBuy => {
+                if let (Some(town), Some(building), Some(player)) = (
+                    managers.world_manager.current_town(),
+                    managers.world_manager.current_building(),
+                    managers.world_manager.player.as_ref(),
+                ) {
+                    if let Some(stock) = managers.world_manager.shop_stock.get(&building.id()) {
+                        let reputation = player.trading_reputation(town.id());
+
+                        self.menu_options.extend(stock.stacks.iter().map(|stack| {
+                            format!(
+                                "{} x{} - {} gold",
+                                stack.item.name,
+                                stack.quantity,
+                                crate::world::shop::buy_price(&stack.item, town.id(), reputation)
+                            )
+                        }));
+                    }
+                }
+                self.menu_options
+                    .extend(OPTIONS_BACK.iter().map(|&option| option.to_string()));
+            }
+            // Shop (list inventory with sell prices)
+            crate::core::states::StateType::ShopSell => {
+                if let (Some(town), Some(player)) = (
+                    managers.world_manager.current_town(),
+                    managers.world_manager.player.as_ref(),
+                ) {
+                    let reputation = player.trading_reputation(town.id());
+
+                    self.menu_options
+                        .extend(player.inventory.stacks.iter().map(|stack| {
+                            format!(
+                                "{} x{} - {} gold",
+                                stack.item.name,
+                                stack.quantity,
+                                crate::world::shop::sell_price(&stack.item, town.id(), reputation)
+                            )
+                        }));
+                }
+                self.menu_options
+                    .extend(OPTIONS_BACK.iter().map(|&option| option.to_string()));
+            }
+            // Inventory (list carried items)
+            crate::core::states::StateType::Inventory => {
+                if let Some(player) = managers.world_manager.player.as_ref() {
+                    self.menu_options.extend(
+                        player
+                            .inventory
+                            .stacks
+                            .iter()
+                            .map(|stack| format!("{} x{}", stack.item.name, stack.quantity)),
+                    );
+                }
+                self.menu_options
+                    .extend(OPTIONS_BACK.iter().map(|&option| option.to_string()));
+            }
+            // Inventory Item
+            crate::core::states::StateType::InventoryItem => {
+                self.menu_options.extend(
+                    OPTIONS_INVENTORY_ITEM
+                        .iter()
+                        .map(|&option| option.to_string()),
+                );
+            }
+            // Conversation
+            crate::core::states::StateType::Conversation => {
+                self.menu_options.extend(
+                    OPTIONS_CONVERSATION
+                        .iter()
+                        .map(|&option| option.to_string()),
+                );
+            }
             // Save Game, Load Game (Error), and Initialize Game (Error)
             crate::core::states::StateType::GameSaveSuccess
             | crate::core::states::StateType::GameSaveError
@@ -75,20 +454,29 @@ impl Menu {
             | crate::core::states::StateType::GameInitError => {
                 self.menu_options
                     .extend(OPTIONS_CONTINUE.iter().map(|&option| option.to_string()));
-            } // Quit Game
-            crate::core::states::StateType::GameQuit => {
+            }
+            // Quit Game and Load Game (Delete Slot)
+            crate::core::states::StateType::GameQuit
+            | crate::core::states::StateType::LoadSlotDelete => {
                 self.menu_options
                     .extend(OPTIONS_GAME_QUIT.iter().map(|&option| option.to_string()));
             }
         };
+
+        // Keep the cursor on the list if it shrank
+        if self.selected_index >= self.menu_options.len() {
+            self.selected_index = self.menu_options.len().saturating_sub(1);
+        }
     }
 
     // Renders the Menu based on current state
-    pub fn render(&self, managers: &super::display::Managers) -> Vec<ListItem> {
+    pub fn render(&self, managers: &super::display::Managers) -> Vec<ListItem<'_>> {
         match managers.state_manager.current_state {
-            // New Game, Save Game, Load Game (Error), and Initialize Game (Error)
+            // New Game, Rename Slot, Conversation, Save Game, Load Game (Error), and Initialize Game (Error)
             crate::core::states::StateType::Name
             | crate::core::states::StateType::NameConfirm
+            | crate::core::states::StateType::LoadSlotRename
+            | crate::core::states::StateType::Conversation
             | crate::core::states::StateType::GameSaveSuccess
             | crate::core::states::StateType::GameSaveError
             | crate::core::states::StateType::GameLoadError
@@ -1,4 +1,5 @@
 pub mod display;
+pub mod map;
 pub mod menu;
 pub mod popup;
 pub mod stats;
@@ -44,6 +44,26 @@ impl Popup {
                 self.display = true;
                 self.title = "Quit Game".into();
             }
+            // Load Game (Rename Slot)
+            crate::core::states::StateType::LoadSlotRename => {
+                self.display = true;
+                self.title = "Rename Save".into();
+            }
+            // Load Game (Delete Slot)
+            crate::core::states::StateType::LoadSlotDelete => {
+                self.display = true;
+                self.title = "Delete Save".into();
+            }
+            // Load Game (Checksum Mismatch)
+            crate::core::states::StateType::LoadChecksumMismatch => {
+                self.display = true;
+                self.title = "Save Corrupted".into();
+            }
+            // Load Game (World Mismatch)
+            crate::core::states::StateType::LoadWorldMismatch => {
+                self.display = true;
+                self.title = "Different World".into();
+            }
             // All other states
             _ => {
                 self.display = false;
@@ -53,7 +73,7 @@ impl Popup {
     }
 
     // Renders the Pop Up based on current state
-    pub fn render(&self, managers: &super::display::Managers) -> (String, Vec<Line>) {
+    pub fn render(&self, managers: &super::display::Managers) -> (String, Vec<Line<'_>>) {
         match managers.state_manager.current_state {
             // New Game (Enter Name)
             crate::core::states::StateType::Name => {
@@ -122,11 +142,80 @@ impl Popup {
             crate::core::states::StateType::GameQuit => {
                 let title = &self.title;
 
-                let text = vec![
+                let mut text = vec![
                     Line::from("\n"),
                     Line::from("Are you sure you want to quit?"),
                 ];
 
+                if managers.config.autosave_on_quit {
+                    text.push(Line::from("Your game will be saved first."));
+                }
+
+                (title.to_string(), text)
+            }
+            // Load Game (Rename Slot)
+            crate::core::states::StateType::LoadSlotRename => {
+                let title = &self.title;
+
+                let prompt = format!("> {}_", self.input);
+
+                let text = vec![
+                    Line::from("\n"),
+                    Line::from("Enter a new name..."),
+                    Line::from(prompt.yellow()),
+                ];
+
+                (title.to_string(), text)
+            }
+            // Load Game (Delete Slot)
+            crate::core::states::StateType::LoadSlotDelete => {
+                let title = &self.title;
+
+                let slot = managers
+                    .save_manager
+                    .selected()
+                    .map(|slot| slot.name.clone())
+                    .unwrap_or_default();
+
+                let text = vec![
+                    Line::from("\n"),
+                    Line::from("Are you sure you want to delete this save?"),
+                    Line::from(slot.yellow()),
+                ];
+
+                (title.to_string(), text)
+            }
+            // Load Game (Checksum Mismatch)
+            crate::core::states::StateType::LoadChecksumMismatch => {
+                let title = &self.title;
+
+                let text = vec![
+                    Line::from("\n"),
+                    Line::from("This save doesn't match its checksum.".red()),
+                    Line::from("It may have been edited or damaged."),
+                    Line::from("Load it anyway or restore a backup?"),
+                ];
+
+                (title.to_string(), text)
+            }
+            // Load Game (World Mismatch)
+            crate::core::states::StateType::LoadWorldMismatch => {
+                let title = &self.title;
+
+                let mut text = vec![
+                    Line::from("\n"),
+                    Line::from("This save was made in a different world.".red()),
+                ];
+
+                if let Some(mismatch) = managers.save_manager.world_mismatch.as_ref() {
+                    text.push(Line::from(format!("Saved in: {}", mismatch.saved.path)));
+                    text.push(Line::from(format!("Loaded: {}", mismatch.current.path)));
+
+                    if !mismatch.matching_available {
+                        text.push(Line::from("The matching world can't be found."));
+                    }
+                }
+
                 (title.to_string(), text)
             }
             // All other states
@@ -1,4 +1,6 @@
+use ratatui::style::Stylize;
 use ratatui::text::Line;
+use strum::IntoEnumIterator;
 
 // Struct for Stats
 pub struct Stats {}
@@ -11,25 +13,89 @@ impl Stats {
     }
 
     // Renders the Stats based on current state
-    pub fn render(&self, managers: &super::display::Managers) -> Vec<Line> {
+    pub fn render(&self, managers: &super::display::Managers) -> Vec<Line<'_>> {
         match managers.state_manager.current_state {
-            // Game, Save Game, Quit Game, Time, Weather, and Travel
+            // Game, Save Game, Quit Game, Time, Weather, Travel, Journey, Map, Explore, Town Map, Conversation, Container, Shop, Tavern, Temple, and Inventory
             crate::core::states::StateType::Game
             | crate::core::states::StateType::GameSaveSuccess
             | crate::core::states::StateType::GameSaveError
             | crate::core::states::StateType::GameQuit
             | crate::core::states::StateType::Time
             | crate::core::states::StateType::Weather
-            | crate::core::states::StateType::Travel => {
+            | crate::core::states::StateType::Travel
+            | crate::core::states::StateType::Journey
+            | crate::core::states::StateType::JourneyEncounter
+            | crate::core::states::StateType::Map
+            | crate::core::states::StateType::Explore
+            | crate::core::states::StateType::TownMap
+            | crate::core::states::StateType::Building
+            | crate::core::states::StateType::Room
+            | crate::core::states::StateType::Conversation
+            | crate::core::states::StateType::Container
+            | crate::core::states::StateType::Shop
+            | crate::core::states::StateType::ShopBuy
+            | crate::core::states::StateType::ShopSell
+            | crate::core::states::StateType::Tavern
+            | crate::core::states::StateType::TavernRumours
+            | crate::core::states::StateType::Temple
+            | crate::core::states::StateType::Inventory
+            | crate::core::states::StateType::InventoryItem => {
                 if let Some(player) = &managers.world_manager.player {
                     let player_id = format!("Player ID: {}", player.id);
                     let player_name = format!("Player Name: {}", player.name);
                     let town_name = format!("Current Town: {}", player.town_name);
-                    vec![
+                    let gold = format!("Gold: {}", player.gold);
+                    let carrying = format!(
+                        "Carrying: {:.1} / {:.1}",
+                        player.inventory.total_weight(),
+                        player.inventory.max_weight
+                    );
+                    let character = format!(
+                        "{:?} {:?} {:?}",
+                        player.character.sex, player.character.race, player.character.background
+                    );
+                    let mut text = vec![
                         Line::from(player_id),
                         Line::from(player_name),
+                        Line::from(character),
                         Line::from(town_name),
-                    ]
+                        Line::from(gold),
+                        Line::from(carrying),
+                        Line::from(""),
+                    ];
+
+                    // Attributes
+                    for attribute in crate::entities::character::Attribute::iter() {
+                        text.push(Line::from(format!(
+                            "{:?}: {}",
+                            attribute,
+                            player.character.attributes.get(attribute)
+                        )));
+                    }
+
+                    // Status effects with the time they have left
+                    if let Some(time) = managers.time_manager.current() {
+                        if !player.effects.effects.is_empty() {
+                            text.push(Line::from(""));
+                            text.push(Line::from("Effects:"));
+                        }
+
+                        for effect in &player.effects.effects {
+                            let line = format!(
+                                "{} ({} ticks)",
+                                effect.kind.name(),
+                                effect.remaining_ticks(&time)
+                            );
+
+                            if effect.kind.harmful() {
+                                text.push(Line::from(line.red()));
+                            } else {
+                                text.push(Line::from(line.green()));
+                            }
+                        }
+                    }
+
+                    text
                 } else {
                     vec![Line::from("Player not initialized")]
                 }
@@ -1,10 +1,15 @@
 use ratatui::style::{Style, Stylize};
 use ratatui::text::{Line, Span};
+use strum::IntoEnumIterator;
 
 // Struct for Viewport
 pub struct Viewport {
     time: String,
     weather: String,
+    pub input: String,
+    pub inventory_index: Option<usize>,
+    pub notice: Option<String>,
+    pub highlighted: usize,
 }
 
 // Functions for Viewport
@@ -14,6 +19,10 @@ impl Viewport {
         Self {
             time: String::new(),
             weather: String::new(),
+            input: String::new(),
+            inventory_index: None,
+            notice: None,
+            highlighted: 0,
         }
     }
 
@@ -62,7 +71,7 @@ impl Viewport {
     }
 
     // Renders the Viewport based on current state
-    pub fn render(&self, managers: &super::display::Managers) -> Vec<Line> {
+    pub fn render(&self, managers: &super::display::Managers) -> Vec<Line<'_>> {
         match managers.state_manager.current_state {
             // Main Menu
             crate::core::states::StateType::MainMenu => {
@@ -86,6 +95,150 @@ impl Viewport {
             crate::core::states::StateType::NameConfirm => {
                 vec![Line::from("Confirm name...")]
             }
+            // New Game (character creation)
+            crate::core::states::StateType::CreateRace
+            | crate::core::states::StateType::CreateSex
+            | crate::core::states::StateType::CreateBackground
+            | crate::core::states::StateType::CreateMethod
+            | crate::core::states::StateType::CreatePointBuy
+            | crate::core::states::StateType::CreateRoll => {
+                let creation = &managers.world_manager.character_creation;
+
+                let prompt = match managers.state_manager.current_state {
+                    crate::core::states::StateType::CreateRace => "Choose your race...",
+                    crate::core::states::StateType::CreateSex => "Choose your sex...",
+                    crate::core::states::StateType::CreateBackground => "Choose your background...",
+                    crate::core::states::StateType::CreateMethod => {
+                        "Spend points on your attributes or roll for them?"
+                    }
+                    crate::core::states::StateType::CreatePointBuy => {
+                        "Use Left and Right to spend your points..."
+                    }
+                    _ => "The dice have spoken...",
+                };
+
+                let mut text = vec![Line::from(prompt), Line::from("\n")];
+
+                match managers.state_manager.current_state {
+                    crate::core::states::StateType::CreateRace => {}
+                    crate::core::states::StateType::CreateSex => {
+                        text.push(Line::from(format!("Race: {:?}", creation.race)));
+                    }
+                    crate::core::states::StateType::CreateBackground => {
+                        text.push(Line::from(format!(
+                            "{:?} {:?}",
+                            creation.sex, creation.race
+                        )));
+                        text.push(Line::from("\n"));
+
+                        for background in crate::entities::character::Background::iter() {
+                            text.push(Line::from(vec![
+                                format!("{:?}", background).green().bold(),
+                                format!(" (+1 {:?})", background.bonus()).into(),
+                            ]));
+                            text.push(Line::from(background.description()));
+                        }
+                    }
+                    _ => {
+                        text.push(Line::from(format!(
+                            "{:?} {:?} {:?}",
+                            creation.sex, creation.race, creation.background
+                        )));
+                        text.push(Line::from("\n"));
+
+                        if matches!(
+                            managers.state_manager.current_state,
+                            crate::core::states::StateType::CreatePointBuy
+                        ) {
+                            text.push(Line::from(
+                                format!("Points left: {}", creation.points_left).yellow(),
+                            ));
+                        }
+
+                        if !matches!(
+                            managers.state_manager.current_state,
+                            crate::core::states::StateType::CreateMethod
+                        ) {
+                            let rolled = matches!(
+                                managers.state_manager.current_state,
+                                crate::core::states::StateType::CreateRoll
+                            );
+
+                            for (index, attribute) in
+                                crate::entities::character::Attribute::iter().enumerate()
+                            {
+                                match creation.rolls.get(index).filter(|_| rolled) {
+                                    Some(roll) => text.push(Line::from(format!(
+                                        "{:?}: {}",
+                                        attribute,
+                                        roll.describe()
+                                    ))),
+                                    None => text.push(Line::from(format!(
+                                        "{:?}: {}",
+                                        attribute,
+                                        creation.attributes.get(attribute)
+                                    ))),
+                                }
+                            }
+
+                            text.push(Line::from(format!(
+                                "Your background adds 1 to {:?}.",
+                                creation.background.bonus()
+                            )));
+                        }
+                    }
+                }
+
+                if let Some(notice) = &self.notice {
+                    text.push(Line::from("\n"));
+                    text.push(Line::from(notice.clone().yellow()));
+                }
+
+                text
+            }
+            // New Game (select world)
+            crate::core::states::StateType::WorldSelect => {
+                let mut text = vec![Line::from("Choose a world to explore..."), Line::from("\n")];
+
+                if managers.world_manager.available_worlds.is_empty() {
+                    text.push(Line::from("No worlds found!".red()));
+                }
+
+                for world_info in &managers.world_manager.available_worlds {
+                    text.push(Line::from(world_info.name.clone().green().bold()));
+                    text.push(Line::from(format!(
+                        "{} towns, {} buildings, {} NPCs in {}",
+                        world_info.towns,
+                        world_info.buildings,
+                        world_info.npcs,
+                        world_info.dir.display()
+                    )));
+                }
+
+                text
+            }
+            // New Game (select starting town)
+            crate::core::states::StateType::TownSelect => {
+                let mut text = vec![
+                    Line::from("Choose where your journey begins..."),
+                    Line::from("\n"),
+                ];
+
+                for town_name in managers.world_manager.town_names() {
+                    let buildings = managers
+                        .world_manager
+                        .town_by_name(&town_name)
+                        .map(|town| town.buildings().len())
+                        .unwrap_or(0);
+
+                    text.push(Line::from(vec![
+                        town_name.green().bold(),
+                        format!(" ({} buildings)", buildings).into(),
+                    ]));
+                }
+
+                text
+            }
             // Game
             crate::core::states::StateType::Game => {
                 let town_name = if let Some(player) = managers.world_manager.player.as_ref() {
@@ -94,24 +247,30 @@ impl Viewport {
                     "Error getting town info!".into()
                 };
 
-                vec![Line::from("The game begins..."), 
-                    Line::from("\n"), 
-                    Line::from("As you’ve already seen, we have a terminal-based UI running with the help of Ratatui."),
-                    Line::from("There are three \"sections\" which all update based on the game's current state."),
-                    Line::from("\n"),
+                let mut text = vec![
                     Line::from(town_name),
                     Line::from("\n"),
-                    Line::from("Select an option from the menu below...")
-                ]
+                    narration(managers),
+                    Line::from("\n"),
+                    Line::from("Select an option from the menu below..."),
+                ];
+
+                if let Some(notice) = &self.notice {
+                    text.push(Line::from("\n"));
+                    text.push(Line::from(notice.clone().yellow()));
+                }
+
+                text
             }
             // Save Game (Success)
             crate::core::states::StateType::GameSaveSuccess => {
                 vec![
                     Line::from("Game saved successfully."),
                     Line::from("\n"),
-                    Line::from(
-                        "All game data has been serialized and saved to JSON file: saves/save.json",
-                    ),
+                    Line::from(format!(
+                        "All game data has been serialized and saved to file: {}",
+                        managers.save_manager.current_save_path().display()
+                    )),
                 ]
             }
             // Save Game (Error)
@@ -123,10 +282,17 @@ impl Viewport {
             }
             // Load Game (Error)
             crate::core::states::StateType::GameLoadError => {
-                vec![Line::from(vec![Span::styled(
+                let mut text = vec![Line::from(vec![Span::styled(
                     "Error loading game!",
                     Style::new().red(),
-                )])]
+                )])];
+
+                if let Some(notice) = &self.notice {
+                    text.push(Line::from("\n"));
+                    text.push(Line::from(notice.clone()));
+                }
+
+                text
             }
             // Initialize Game (Error)
             crate::core::states::StateType::GameInitError => {
@@ -135,6 +301,46 @@ impl Viewport {
                     Style::new().red(),
                 )])]
             }
+            // Load Game (list save slots)
+            crate::core::states::StateType::LoadGame => {
+                let mut text = Vec::new();
+
+                if managers.save_manager.slots.is_empty() {
+                    text.push(Line::from("No saved games found."));
+                } else {
+                    text.push(Line::from("Choose a save to load, rename or delete."));
+
+                    for slot in &managers.save_manager.slots {
+                        text.push(Line::from("\n"));
+                        text.extend(describe_slot(slot));
+                    }
+                }
+
+                if let Some(notice) = &self.notice {
+                    text.push(Line::from("\n"));
+                    text.push(Line::from(notice.clone().yellow()));
+                }
+
+                text
+            }
+            // Load Game (selected slot, rename and delete)
+            crate::core::states::StateType::LoadSlot
+            | crate::core::states::StateType::LoadSlotRename
+            | crate::core::states::StateType::LoadSlotDelete
+            | crate::core::states::StateType::LoadChecksumMismatch
+            | crate::core::states::StateType::LoadWorldMismatch => {
+                let mut text = match managers.save_manager.selected() {
+                    Some(slot) => describe_slot(slot),
+                    None => vec![Line::from("Error getting save info!")],
+                };
+
+                if let Some(notice) = &self.notice {
+                    text.push(Line::from("\n"));
+                    text.push(Line::from(notice.clone().yellow()));
+                }
+
+                text
+            }
             // Quit Game
             crate::core::states::StateType::GameQuit => {
                 vec![Line::from("Are you sure you want to quit?")]
@@ -157,8 +363,680 @@ impl Viewport {
             }
             // Travel
             crate::core::states::StateType::Travel => {
-                vec![Line::from("Where would you like to go?")]
+                let mut text = vec![Line::from("Where would you like to go?")];
+
+                if let Some(player) = managers.world_manager.player.as_ref() {
+                    text.push(Line::from(format!("You have {} gold.", player.gold)));
+
+                    // Route breakdown for the highlighted destination
+                    if let Some(destination) = managers
+                        .world_manager
+                        .travel_destinations()
+                        .get(self.highlighted)
+                    {
+                        text.push(Line::from("\n"));
+
+                        match managers
+                            .world_manager
+                            .plan_route(&player.town_name, destination)
+                        {
+                            Ok(route) => {
+                                text.push(Line::from(
+                                    format!(
+                                        "Route to {}: {}",
+                                        destination,
+                                        route.towns.join(" > ")
+                                    )
+                                    .green(),
+                                ));
+
+                                for leg in &route.legs {
+                                    text.push(Line::from(format!(
+                                        "  {} to {}: {} miles, {} gold",
+                                        leg.from,
+                                        leg.to,
+                                        leg.distance,
+                                        player.effects.adjust_fare(leg.cost)
+                                    )));
+                                }
+
+                                text.push(Line::from(format!(
+                                    "Total: {} miles, {} gold",
+                                    route.distance(),
+                                    route.fare(&player.effects)
+                                )));
+
+                                if let Some(time) = managers.time_manager.current() {
+                                    text.push(Line::from(format!(
+                                        "ETA: {}",
+                                        route.eta(&time, &player.effects).describe()
+                                    )));
+                                }
+                            }
+                            Err(e) => text.push(Line::from(e.to_string().red())),
+                        }
+                    }
+                }
+
+                if let Some(notice) = &self.notice {
+                    text.push(Line::from("\n"));
+                    text.push(Line::from(notice.clone().red()));
+                }
+
+                text
+            }
+            // Journey and Journey (encounter on the road)
+            crate::core::states::StateType::Journey
+            | crate::core::states::StateType::JourneyEncounter => {
+                let Some(journey) = managers.world_manager.journey.as_ref() else {
+                    return vec![Line::from("Error getting journey info!")];
+                };
+
+                let mut text = vec![
+                    Line::from(format!("You are on the road to {}.", journey.destination)),
+                    Line::from("\n"),
+                    Line::from(progress_bar(journey.progress()).green()),
+                ];
+
+                if let Some(leg) = journey.current_leg() {
+                    text.push(Line::from(format!(
+                        "Leg {} of {}: {} to {} ({} miles, {} / {} ticks)",
+                        journey.leg + 1,
+                        journey.legs.len(),
+                        leg.from,
+                        leg.to,
+                        leg.distance,
+                        journey.leg_progress,
+                        journey.leg_length
+                    )));
+                }
+
+                let mut route: Vec<&str> =
+                    journey.legs.iter().map(|leg| leg.from.as_str()).collect();
+                route.push(&journey.destination);
+                text.push(Line::from(format!("Route: {}", route.join(" > "))));
+
+                if journey.stop_early {
+                    text.push(Line::from("You'll stop at the end of this leg.".yellow()));
+                }
+
+                if let Some(encounter) = journey.encounter.as_ref() {
+                    text.push(Line::from("\n"));
+                    text.push(Line::from(encounter.describe().red().bold()));
+                }
+
+                if let Some(notice) = &self.notice {
+                    text.push(Line::from("\n"));
+                    text.push(Line::from(notice.clone().yellow()));
+                }
+
+                text
+            }
+            // Map
+            crate::core::states::StateType::Map => {
+                let mut text = vec![
+                    Line::from("@ You are here".green()),
+                    Line::from("* Route".yellow()),
+                    Line::from("* Town"),
+                    Line::from("\n"),
+                ];
+
+                if let Some(journey) = managers.world_manager.journey.as_ref() {
+                    text.push(Line::from(format!(
+                        "On the road to {}.",
+                        journey.destination
+                    )));
+                } else if let Some(player) = managers.world_manager.player.as_ref() {
+                    match managers
+                        .world_manager
+                        .travel_destinations()
+                        .get(self.highlighted)
+                        .map(|destination| {
+                            managers
+                                .world_manager
+                                .plan_route(&player.town_name, destination)
+                        }) {
+                        Some(Ok(route)) => {
+                            text.push(Line::from(route.towns.join(" > ")));
+                            text.push(Line::from(format!(
+                                "{} miles, {} gold",
+                                route.distance(),
+                                route.fare(&player.effects)
+                            )));
+                        }
+                        Some(Err(e)) => text.push(Line::from(e.to_string().red())),
+                        None => {}
+                    }
+                }
+
+                text
+            }
+            // Explore
+            crate::core::states::StateType::Explore => {
+                if let Some(town) = managers.world_manager.current_town() {
+                    let mut text = vec![
+                        Line::from(format!("You wander the streets of {}.", town.name)),
+                        narration(managers),
+                        Line::from("\n"),
+                    ];
+
+                    text.extend(town.buildings().iter().map(|building| {
+                        Line::from(format!(
+                            "{} ({:?})",
+                            building.name(),
+                            building.building_type()
+                        ))
+                    }));
+                    text.push(Line::from("\n"));
+                    text.push(Line::from("Which building would you like to enter?"));
+
+                    text
+                } else {
+                    vec![Line::from("Error getting town info!")]
+                }
+            }
+            // Town Map
+            crate::core::states::StateType::TownMap => {
+                let (Some(town), Some(player)) = (
+                    managers.world_manager.current_town(),
+                    managers.world_manager.player.as_ref(),
+                ) else {
+                    return vec![Line::from("Error getting town info!")];
+                };
+
+                let mut text = vec![
+                    Line::from(format!("The streets of {}", town.name)),
+                    Line::from("\n"),
+                ];
+                text.extend(town_grid(town, player.position));
+                text.push(Line::from("\n"));
+
+                match town.building_at(player.position) {
+                    Some(building) => text.push(Line::from(format!(
+                        "You stand outside {} ({:?}).",
+                        building.name(),
+                        building.building_type()
+                    ))),
+                    None => text.push(Line::from("You stand in an empty street.")),
+                }
+
+                text.push(Line::from(
+                    "[ ] You  h Residence  $ Shop  T Tavern  + Temple  . Street".dark_gray(),
+                ));
+
+                if let Some(notice) = &self.notice {
+                    text.push(Line::from("\n"));
+                    text.push(Line::from(notice.clone().yellow()));
+                }
+
+                text
+            }
+            // Building
+            crate::core::states::StateType::Building => {
+                if let Some(building) = managers.world_manager.current_building() {
+                    let mut text = vec![
+                        Line::from(format!(
+                            "You enter {} ({:?}).",
+                            building.name(),
+                            building.building_type()
+                        )),
+                        narration(managers),
+                        Line::from("\n"),
+                    ];
+
+                    for (i, room) in building.rooms().iter().enumerate() {
+                        text.push(Line::from(Span::styled(
+                            format!("Room {}", i + 1),
+                            Style::new().bold(),
+                        )));
+                        text.extend(describe_room(room));
+                    }
+
+                    text
+                } else {
+                    vec![Line::from("Error getting building info!")]
+                }
+            }
+            // Container
+            crate::core::states::StateType::Container => {
+                let container = managers.world_manager.current_container();
+                let contents = container.and_then(|container| {
+                    managers
+                        .world_manager
+                        .container_contents
+                        .get(&container.id())
+                });
+
+                if let (Some(container), Some(contents)) = (container, contents) {
+                    let mut text = vec![
+                        Line::from(format!("You search the {:?}.", container.container_type())),
+                        Line::from("\n"),
+                    ];
+
+                    if contents.is_empty() {
+                        text.push(Line::from("It's empty."));
+                    } else {
+                        text.extend(contents.iter().map(|stack| {
+                            Line::from(format!(
+                                "{} x{} ({:?}, {:.1} weight each)",
+                                stack.item.name, stack.quantity, stack.item.kind, stack.item.weight
+                            ))
+                        }));
+                    }
+
+                    if let Some(notice) = &self.notice {
+                        text.push(Line::from("\n"));
+                        text.push(Line::from(notice.clone().yellow()));
+                    }
+
+                    text
+                } else {
+                    vec![Line::from("Error getting container info!")]
+                }
+            }
+            // Tavern
+            crate::core::states::StateType::Tavern => {
+                if let (Some(building), Some(player)) = (
+                    managers.world_manager.current_building(),
+                    managers.world_manager.player.as_ref(),
+                ) {
+                    let greeting = match building.owner() {
+                        Some(owner) => format!(
+                            "{} wipes down the bar of {} and nods at you.",
+                            owner.name(),
+                            building.name()
+                        ),
+                        None => format!("Nobody is tending the bar of {}.", building.name()),
+                    };
+
+                    let mut text = vec![
+                        Line::from(greeting),
+                        Line::from("\n"),
+                        narration(managers),
+                        Line::from("\n"),
+                        Line::from(format!("You have {} gold.", player.gold)),
+                    ];
+
+                    if let Some(notice) = &self.notice {
+                        text.push(Line::from("\n"));
+                        text.push(Line::from(notice.clone().yellow()));
+                    }
+
+                    text
+                } else {
+                    vec![Line::from("Error getting tavern info!")]
+                }
+            }
+            // Temple
+            crate::core::states::StateType::Temple => {
+                if let (Some(building), Some(player)) = (
+                    managers.world_manager.current_building(),
+                    managers.world_manager.player.as_ref(),
+                ) {
+                    let greeting = match building.owner() {
+                        Some(owner) => format!(
+                            "{} greets you softly in the halls of {}.",
+                            owner.name(),
+                            building.name()
+                        ),
+                        None => format!("The halls of {} are silent.", building.name()),
+                    };
+
+                    let mut text = vec![
+                        Line::from(greeting),
+                        Line::from("\n"),
+                        narration(managers),
+                        Line::from("\n"),
+                        Line::from("Blessings and healing are offered here for a donation."),
+                        Line::from(format!("You have {} gold.", player.gold)),
+                    ];
+
+                    if let Some(notice) = &self.notice {
+                        text.push(Line::from("\n"));
+                        text.push(Line::from(notice.clone().yellow()));
+                    }
+
+                    text
+                } else {
+                    vec![Line::from("Error getting temple info!")]
+                }
+            }
+            // Tavern (rumours)
+            crate::core::states::StateType::TavernRumours => {
+                match managers.world_manager.current_building() {
+                    Some(building) => {
+                        let day = managers.time_manager.current().map_or(1, |t| t.day);
+                        let rumours = crate::world::tavern::rumours(
+                            &managers.world_manager,
+                            building.id(),
+                            day,
+                        );
+
+                        let mut text = vec![
+                            Line::from("You lean on the bar and listen to the talk."),
+                            Line::from("\n"),
+                        ];
+
+                        if rumours.is_empty() {
+                            text.push(Line::from("Nobody has anything worth hearing today."));
+                        } else {
+                            text.extend(
+                                rumours
+                                    .into_iter()
+                                    .map(|rumour| Line::from(format!("\"{}\"", rumour))),
+                            );
+                        }
+
+                        text
+                    }
+                    None => vec![Line::from("Error getting tavern info!")],
+                }
+            }
+            // Shop
+            crate::core::states::StateType::Shop
+            | crate::core::states::StateType::ShopBuy
+            | crate::core::states::StateType::ShopSell => {
+                if let (Some(town), Some(building), Some(player)) = (
+                    managers.world_manager.current_town(),
+                    managers.world_manager.current_building(),
+                    managers.world_manager.player.as_ref(),
+                ) {
+                    let greeting = match building.owner() {
+                        Some(owner) => format!(
+                            "{} looks up from behind the counter of {}.",
+                            owner.name(),
+                            building.name()
+                        ),
+                        None => format!("The counter of {} is unattended.", building.name()),
+                    };
+
+                    let mut text = vec![
+                        Line::from(greeting),
+                        Line::from("\n"),
+                        Line::from(format!("You have {} gold.", player.gold)),
+                        Line::from(format!(
+                            "Your reputation in {} is {}.",
+                            town.name,
+                            player.reputation_in(town.id())
+                        )),
+                    ];
+
+                    match managers.state_manager.current_state {
+                        crate::core::states::StateType::ShopBuy => {
+                            text.push(Line::from("What would you like to buy?"));
+                        }
+                        crate::core::states::StateType::ShopSell => {
+                            text.push(Line::from("What would you like to sell?"));
+                        }
+                        _ => {}
+                    }
+
+                    if let Some(notice) = &self.notice {
+                        text.push(Line::from("\n"));
+                        text.push(Line::from(notice.clone().yellow()));
+                    }
+
+                    text
+                } else {
+                    vec![Line::from("Error getting shop info!")]
+                }
+            }
+            // Inventory
+            crate::core::states::StateType::Inventory => {
+                if let Some(player) = managers.world_manager.player.as_ref() {
+                    let inventory = &player.inventory;
+
+                    let mut text = vec![
+                        Line::from(format!(
+                            "You are carrying {:.1} / {:.1} weight in {} / {} slots.",
+                            inventory.total_weight(),
+                            inventory.max_weight,
+                            inventory.stacks.len(),
+                            inventory.max_slots
+                        )),
+                        Line::from("\n"),
+                    ];
+
+                    if inventory.stacks.is_empty() {
+                        text.push(Line::from("Your pack is empty."));
+                    } else {
+                        text.push(Line::from("Select an item to inspect it."));
+                    }
+
+                    text
+                } else {
+                    vec![Line::from("Error getting player info!")]
+                }
+            }
+            // Inventory Item
+            crate::core::states::StateType::InventoryItem => {
+                let stack = self.inventory_index.and_then(|index| {
+                    managers
+                        .world_manager
+                        .player
+                        .as_ref()?
+                        .inventory
+                        .stacks
+                        .get(index)
+                });
+
+                if let Some(stack) = stack {
+                    vec![
+                        Line::from(Span::styled(stack.item.name.clone(), Style::new().bold())),
+                        Line::from("\n"),
+                        Line::from(format!("Kind: {:?}", stack.item.kind)),
+                        Line::from(format!(
+                            "Quantity: {} (stacks to {})",
+                            stack.quantity, stack.item.max_stack
+                        )),
+                        Line::from(format!(
+                            "Weight: {:.1} each, {:.1} total",
+                            stack.item.weight,
+                            stack.item.weight * stack.quantity as f32
+                        )),
+                        Line::from(format!(
+                            "Value: {} gold each, {} total",
+                            stack.item.value,
+                            stack.item.value * stack.quantity
+                        )),
+                    ]
+                } else {
+                    vec![Line::from("Error getting item info!")]
+                }
+            }
+            // Conversation
+            crate::core::states::StateType::Conversation => {
+                let npc = managers.dialogue_manager.npc_id.and_then(|npc_id| {
+                    managers
+                        .world_manager
+                        .current_room()?
+                        .npcs()
+                        .iter()
+                        .find(|npc| npc.id() == npc_id)
+                });
+
+                if let Some(npc) = npc {
+                    let mut text = vec![
+                        Line::from(format!(
+                            "You are talking to {} ({:?} {:?}).",
+                            npc.name(),
+                            npc.sex(),
+                            npc.race()
+                        )),
+                        Line::from("\n"),
+                    ];
+
+                    let transcript = managers
+                        .dialogue_manager
+                        .current_memory()
+                        .map(|memory| memory.transcript.as_slice())
+                        .unwrap_or_default();
+
+                    for line in transcript {
+                        let speaker = match line.speaker {
+                            crate::ai::dialogue::Speaker::Player => {
+                                Span::styled("You: ", Style::new().yellow().bold())
+                            }
+                            crate::ai::dialogue::Speaker::Npc => Span::styled(
+                                format!("{}: ", npc.name()),
+                                Style::new().green().bold(),
+                            ),
+                        };
+
+                        text.push(Line::from(vec![speaker, Span::raw(line.text.clone())]));
+                    }
+
+                    // Reply still being generated
+                    if let Some(generation) = &managers.dialogue_manager.generation_arc_rwlock {
+                        let partial = generation
+                            .read()
+                            .map(|generation| generation.text.clone())
+                            .unwrap_or_default();
+
+                        let text_so_far = if partial.is_empty() {
+                            Span::styled("...", Style::new().italic())
+                        } else {
+                            Span::raw(partial)
+                        };
+
+                        text.push(Line::from(vec![
+                            Span::styled(format!("{}: ", npc.name()), Style::new().green().bold()),
+                            text_so_far,
+                        ]));
+                    }
+
+                    if let Some(error) = &managers.dialogue_manager.error {
+                        text.push(Line::from(Span::styled(error.clone(), Style::new().red())));
+                    }
+
+                    text.push(Line::from("\n"));
+                    text.push(Line::from(format!("> {}_", self.input).yellow()));
+
+                    text
+                } else {
+                    vec![Line::from("Error getting NPC info!")]
+                }
+            }
+            // Room
+            crate::core::states::StateType::Room => {
+                if let Some(room) = managers.world_manager.current_room() {
+                    let mut text = vec![
+                        Line::from("You look around the room."),
+                        narration(managers),
+                        Line::from("\n"),
+                    ];
+                    text.extend(describe_room(room));
+
+                    text
+                } else {
+                    vec![Line::from("Error getting room info!")]
+                }
+            }
+        }
+    }
+}
+
+// Lists the NPCs and containers in a room
+fn describe_room(room: &crate::world::manager::Room) -> Vec<Line<'static>> {
+    let npcs = if room.npcs().is_empty() {
+        "Nobody".to_string()
+    } else {
+        room.npcs()
+            .iter()
+            .map(|npc| format!("{} ({:?} {:?})", npc.name(), npc.sex(), npc.race()))
+            .collect::<Vec<String>>()
+            .join(", ")
+    };
+
+    let containers = if room.containers().is_empty() {
+        "Nothing".to_string()
+    } else {
+        room.containers()
+            .iter()
+            .map(|container| format!("{:?}", container.container_type()))
+            .collect::<Vec<String>>()
+            .join(", ")
+    };
+
+    vec![
+        Line::from(format!("  People: {}", npcs)),
+        Line::from(format!("  Containers: {}", containers)),
+    ]
+}
+
+// Describe a save slot for the load browser
+fn describe_slot(slot: &crate::core::save::SaveSlot) -> Vec<Line<'static>> {
+    let mut text = vec![Line::from(slot.name.clone().green().bold())];
+
+    match &slot.metadata {
+        Some(metadata) => {
+            text.push(Line::from(format!(
+                "{} in {}, day {}",
+                metadata.player_name, metadata.town_name, metadata.day
+            )));
+            text.push(Line::from(format!(
+                "Saved {}, played {}",
+                metadata.saved_at_display(),
+                metadata.play_time_display()
+            )));
+        }
+        None => text.push(Line::from("No details saved.")),
+    }
+
+    text
+}
+
+// Narrator's description of the current scene
+fn narration(managers: &super::display::Managers) -> Line<'static> {
+    match managers.narrator_manager.description() {
+        Some(description) => Line::from(description.italic()),
+        None => Line::from("...".italic()),
+    }
+}
+
+// Draw a progress bar for a fraction from 0 to 1
+fn progress_bar(fraction: f64) -> String {
+    const WIDTH: usize = 30;
+
+    let filled = ((fraction.clamp(0.0, 1.0) * WIDTH as f64).round() as usize).min(WIDTH);
+
+    format!(
+        "[{}{}] {:>3}%",
+        "#".repeat(filled),
+        "-".repeat(WIDTH - filled),
+        (fraction * 100.0).round() as u32
+    )
+}
+
+// Draw a town's street grid, each building as its glyph joined by streets, with the player's cell in brackets
+fn town_grid(town: &crate::world::manager::Town, position: (u32, u32)) -> Vec<Line<'static>> {
+    let (width, height) = town.grid_size();
+    let mut lines = Vec::new();
+
+    for y in 0..height {
+        let mut row = Vec::new();
+
+        for x in 0..width {
+            if x > 0 {
+                row.push(Span::from("─").dark_gray());
+            }
+
+            let glyph = town
+                .building_at((x, y))
+                .map_or('.', |building| building.building_type().glyph());
+
+            if (x, y) == position {
+                row.push(Span::from(format!("[{}]", glyph)).green().bold());
+            } else {
+                row.push(Span::from(format!(" {} ", glyph)));
             }
         }
+
+        if y > 0 {
+            lines.push(Line::from(" │  ".repeat(width as usize)).dark_gray());
+        }
+        lines.push(Line::from(row));
     }
+
+    lines
 }
@@ -0,0 +1,867 @@
+use std::collections::HashSet;
+
+// Struct for a graph read from a DOT file, every node in the order first seen and every edge
+#[derive(Debug, Default)]
+pub struct DotGraph {
+    pub nodes: Vec<String>,
+    pub edges: Vec<DotEdge>,
+}
+
+// Struct for an edge in a DOT file, with where it was written so problems with it can be reported
+#[derive(Debug, Clone)]
+pub struct DotEdge {
+    pub from: String,
+    pub to: String,
+    attributes: Vec<(String, String)>,
+    pub line: usize,
+    pub column: usize,
+}
+
+// Functions for DOT Edge
+impl DotEdge {
+    // Get an attribute, the last one given wins like in Graphviz
+    pub fn attribute(&self, name: &str) -> Option<&str> {
+        self.attributes
+            .iter()
+            .rev()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+// Struct for a DOT file that couldn't be parsed
+#[derive(Debug)]
+pub struct DotError {
+    pub line: usize,
+    pub column: usize,
+    pub message: String,
+}
+
+impl std::fmt::Display for DotError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.message
+        )
+    }
+}
+
+impl std::error::Error for DotError {}
+
+// Parse a DOT file: node, edge, attribute and subgraph statements, with comments and quoted, HTML or numeral ids
+pub fn parse(input: &str) -> Result<DotGraph, DotError> {
+    let tokens = Lexer::new(input).tokens()?;
+
+    Parser::new(tokens).graph()
+}
+
+// Enum for the pieces a DOT file is made of
+#[derive(Debug, Clone, PartialEq)]
+enum TokenKind {
+    Id { value: String, quoted: bool },
+    EdgeOp { directed: bool },
+    OpenBrace,
+    CloseBrace,
+    OpenBracket,
+    CloseBracket,
+    Equals,
+    Semicolon,
+    Comma,
+    Colon,
+    Plus,
+    End,
+}
+
+// Functions for Token Kind
+impl TokenKind {
+    // Describe the token for error messages
+    fn describe(&self) -> String {
+        match self {
+            TokenKind::Id { value, .. } => format!("`{}`", value),
+            TokenKind::EdgeOp { directed: true } => "`->`".to_string(),
+            TokenKind::EdgeOp { directed: false } => "`--`".to_string(),
+            TokenKind::OpenBrace => "`{`".to_string(),
+            TokenKind::CloseBrace => "`}`".to_string(),
+            TokenKind::OpenBracket => "`[`".to_string(),
+            TokenKind::CloseBracket => "`]`".to_string(),
+            TokenKind::Equals => "`=`".to_string(),
+            TokenKind::Semicolon => "`;`".to_string(),
+            TokenKind::Comma => "`,`".to_string(),
+            TokenKind::Colon => "`:`".to_string(),
+            TokenKind::Plus => "`+`".to_string(),
+            TokenKind::End => "the end of the file".to_string(),
+        }
+    }
+
+    // Check for a keyword, which are case insensitive and never quoted
+    fn is_keyword(&self, keyword: &str) -> bool {
+        match self {
+            TokenKind::Id {
+                value,
+                quoted: false,
+            } => value.eq_ignore_ascii_case(keyword),
+            _ => false,
+        }
+    }
+}
+
+// Struct for a token and where it starts
+#[derive(Debug, Clone)]
+struct Token {
+    kind: TokenKind,
+    line: usize,
+    column: usize,
+}
+
+// Struct for splitting a DOT file into tokens
+struct Lexer {
+    chars: Vec<char>,
+    index: usize,
+    line: usize,
+    column: usize,
+}
+
+// Functions for Lexer
+impl Lexer {
+    fn new(input: &str) -> Self {
+        Self {
+            chars: input.chars().collect(),
+            index: 0,
+            line: 1,
+            column: 1,
+        }
+    }
+
+    fn peek(&self, offset: usize) -> Option<char> {
+        self.chars.get(self.index + offset).copied()
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let c = self.peek(0)?;
+        self.index += 1;
+
+        if c == '\n' {
+            self.line += 1;
+            self.column = 1;
+        } else {
+            self.column += 1;
+        }
+
+        Some(c)
+    }
+
+    fn error(&self, line: usize, column: usize, message: &str) -> DotError {
+        DotError {
+            line,
+            column,
+            message: message.to_string(),
+        }
+    }
+
+    fn tokens(mut self) -> Result<Vec<Token>, DotError> {
+        let mut tokens = Vec::new();
+
+        loop {
+            self.skip_ignored()?;
+
+            let (line, column) = (self.line, self.column);
+
+            let Some(c) = self.peek(0) else {
+                tokens.push(Token {
+                    kind: TokenKind::End,
+                    line,
+                    column,
+                });
+
+                return Ok(tokens);
+            };
+
+            let kind = match c {
+                '{' | '}' | '[' | ']' | '=' | ';' | ',' | ':' | '+' => {
+                    self.bump();
+
+                    match c {
+                        '{' => TokenKind::OpenBrace,
+                        '}' => TokenKind::CloseBrace,
+                        '[' => TokenKind::OpenBracket,
+                        ']' => TokenKind::CloseBracket,
+                        '=' => TokenKind::Equals,
+                        ';' => TokenKind::Semicolon,
+                        ',' => TokenKind::Comma,
+                        ':' => TokenKind::Colon,
+                        _ => TokenKind::Plus,
+                    }
+                }
+                '-' if self.peek(1) == Some('-') || self.peek(1) == Some('>') => {
+                    self.bump();
+                    let directed = self.bump() == Some('>');
+
+                    TokenKind::EdgeOp { directed }
+                }
+                '"' => self.quoted(line, column)?,
+                '<' => self.html(line, column)?,
+                c if c == '-' || c == '.' || c.is_ascii_digit() => self.numeral(line, column)?,
+                c if c == '_' || c.is_alphabetic() || !c.is_ascii() => self.identifier(),
+                c => {
+                    return Err(self.error(line, column, &format!("unexpected character `{}`", c)));
+                }
+            };
+
+            tokens.push(Token { kind, line, column });
+        }
+    }
+
+    // Skip whitespace, comments, and lines starting with # which Graphviz treats as preprocessor output
+    fn skip_ignored(&mut self) -> Result<(), DotError> {
+        loop {
+            match (self.peek(0), self.peek(1)) {
+                (Some(c), _) if c.is_whitespace() => {
+                    self.bump();
+                }
+                (Some('#'), _) if self.at_line_start() => self.skip_line(),
+                (Some('/'), Some('/')) => self.skip_line(),
+                (Some('/'), Some('*')) => {
+                    let (line, column) = (self.line, self.column);
+                    self.bump();
+                    self.bump();
+
+                    loop {
+                        match (self.peek(0), self.peek(1)) {
+                            (Some('*'), Some('/')) => {
+                                self.bump();
+                                self.bump();
+                                break;
+                            }
+                            (Some(_), _) => {
+                                self.bump();
+                            }
+                            (None, _) => {
+                                return Err(self.error(line, column, "unterminated comment"));
+                            }
+                        }
+                    }
+                }
+                _ => return Ok(()),
+            }
+        }
+    }
+
+    fn at_line_start(&self) -> bool {
+        self.chars[..self.index]
+            .iter()
+            .rev()
+            .take_while(|&&c| c != '\n')
+            .all(|c| c.is_whitespace())
+    }
+
+    fn skip_line(&mut self) {
+        while self.peek(0).is_some_and(|c| c != '\n') {
+            self.bump();
+        }
+    }
+
+    // A double quoted string, \" is a quote and a backslash before a newline joins the lines
+    fn quoted(&mut self, line: usize, column: usize) -> Result<TokenKind, DotError> {
+        self.bump();
+
+        let mut value = String::new();
+
+        loop {
+            match self.bump() {
+                Some('"') => break,
+                Some('\\') => match self.peek(0) {
+                    Some('"') => {
+                        self.bump();
+                        value.push('"');
+                    }
+                    Some('\n') => {
+                        self.bump();
+                    }
+                    Some('\r') if self.peek(1) == Some('\n') => {
+                        self.bump();
+                        self.bump();
+                    }
+                    // Other escapes like \n are kept for Graphviz to interpret
+                    _ => value.push('\\'),
+                },
+                Some(c) => value.push(c),
+                None => return Err(self.error(line, column, "unterminated string")),
+            }
+        }
+
+        Ok(TokenKind::Id {
+            value,
+            quoted: true,
+        })
+    }
+
+    // An HTML string between angle brackets, which can nest
+    fn html(&mut self, line: usize, column: usize) -> Result<TokenKind, DotError> {
+        self.bump();
+
+        let mut value = String::new();
+        let mut depth = 1;
+
+        loop {
+            match self.bump() {
+                Some('<') => depth += 1,
+                Some('>') => {
+                    depth -= 1;
+
+                    if depth == 0 {
+                        break;
+                    }
+                }
+                Some(_) => {}
+                None => return Err(self.error(line, column, "unterminated HTML string")),
+            }
+
+            value.push(self.chars[self.index - 1]);
+        }
+
+        Ok(TokenKind::Id {
+            value,
+            quoted: true,
+        })
+    }
+
+    // A number like 12, -3.5 or .5
+    fn numeral(&mut self, line: usize, column: usize) -> Result<TokenKind, DotError> {
+        let mut value = String::new();
+
+        if self.peek(0) == Some('-') {
+            self.bump();
+            value.push('-');
+        }
+
+        let mut seen_point = false;
+
+        while let Some(c) = self.peek(0) {
+            if c.is_ascii_digit() || (c == '.' && !seen_point) {
+                seen_point |= c == '.';
+                value.push(c);
+                self.bump();
+            } else {
+                break;
+            }
+        }
+
+        if !value.chars().any(|c| c.is_ascii_digit()) {
+            return Err(self.error(line, column, &format!("invalid number `{}`", value)));
+        }
+
+        Ok(TokenKind::Id {
+            value,
+            quoted: false,
+        })
+    }
+
+    // A name made of letters, digits and underscores, not starting with a digit
+    fn identifier(&mut self) -> TokenKind {
+        let mut value = String::new();
+
+        while let Some(c) = self.peek(0) {
+            if c == '_' || c.is_alphanumeric() || !c.is_ascii() {
+                value.push(c);
+                self.bump();
+            } else {
+                break;
+            }
+        }
+
+        TokenKind::Id {
+            value,
+            quoted: false,
+        }
+    }
+}
+
+// Struct for building a graph from tokens, following the Graphviz grammar
+struct Parser {
+    tokens: Vec<Token>,
+    index: usize,
+    directed: bool,
+    graph: DotGraph,
+    seen: HashSet<String>,
+    edge_defaults: Vec<(String, String)>,
+}
+
+// Functions for Parser
+impl Parser {
+    fn new(tokens: Vec<Token>) -> Self {
+        Self {
+            tokens,
+            index: 0,
+            directed: false,
+            graph: DotGraph::default(),
+            seen: HashSet::new(),
+            edge_defaults: Vec::new(),
+        }
+    }
+
+    fn peek(&self) -> &Token {
+        &self.tokens[self.index.min(self.tokens.len() - 1)]
+    }
+
+    fn peek_kind(&self, offset: usize) -> &TokenKind {
+        &self.tokens[(self.index + offset).min(self.tokens.len() - 1)].kind
+    }
+
+    fn next(&mut self) -> Token {
+        let token = self.peek().clone();
+
+        if token.kind != TokenKind::End {
+            self.index += 1;
+        }
+
+        token
+    }
+
+    fn eat(&mut self, kind: &TokenKind) -> bool {
+        if self.peek_kind(0) == kind {
+            self.next();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn error(&self, token: &Token, expected: &str) -> DotError {
+        DotError {
+            line: token.line,
+            column: token.column,
+            message: format!("expected {}, found {}", expected, token.kind.describe()),
+        }
+    }
+
+    fn expect(&mut self, kind: &TokenKind, expected: &str) -> Result<(), DotError> {
+        let token = self.next();
+
+        if token.kind == *kind {
+            Ok(())
+        } else {
+            Err(self.error(&token, expected))
+        }
+    }
+
+    // An id, joining quoted strings written "like" + "this"
+    fn id(&mut self, expected: &str) -> Result<String, DotError> {
+        let token = self.next();
+
+        let TokenKind::Id { value, quoted } = token.kind.clone() else {
+            return Err(self.error(&token, expected));
+        };
+
+        if !quoted && is_reserved(&value) {
+            return Err(DotError {
+                line: token.line,
+                column: token.column,
+                message: format!("`{}` is a keyword, quote it to use it as a name", value),
+            });
+        }
+
+        let mut value = value;
+
+        while quoted && self.peek_kind(0) == &TokenKind::Plus {
+            self.next();
+
+            let token = self.next();
+
+            match token.kind {
+                TokenKind::Id {
+                    value: more,
+                    quoted: true,
+                } => value.push_str(&more),
+                _ => return Err(self.error(&token, "a quoted string after `+`")),
+            }
+        }
+
+        Ok(value)
+    }
+
+    // graph : [strict] (graph | digraph) [ID] '{' stmt_list '}'
+    fn graph(mut self) -> Result<DotGraph, DotError> {
+        if self.peek().kind.is_keyword("strict") {
+            self.next();
+        }
+
+        let token = self.next();
+
+        if token.kind.is_keyword("digraph") {
+            self.directed = true;
+        } else if !token.kind.is_keyword("graph") {
+            return Err(self.error(&token, "`graph` or `digraph`"));
+        }
+
+        if matches!(self.peek_kind(0), TokenKind::Id { .. }) {
+            self.id("a graph name")?;
+        }
+
+        self.expect(&TokenKind::OpenBrace, "`{`")?;
+        self.statements()?;
+
+        let token = self.next();
+
+        if token.kind != TokenKind::End {
+            return Err(self.error(&token, "the end of the file after the graph"));
+        }
+
+        Ok(self.graph)
+    }
+
+    // stmt_list up to and including the closing brace, returns every node mentioned
+    fn statements(&mut self) -> Result<Vec<String>, DotError> {
+        let mut nodes = Vec::new();
+
+        loop {
+            match self.peek_kind(0) {
+                TokenKind::CloseBrace => {
+                    self.next();
+
+                    return Ok(nodes);
+                }
+                TokenKind::End => {
+                    let token = self.peek().clone();
+
+                    return Err(self.error(&token, "`}`"));
+                }
+                _ => {
+                    nodes.extend(self.statement()?);
+                    self.eat(&TokenKind::Semicolon);
+                }
+            }
+        }
+    }
+
+    // stmt : node_stmt | edge_stmt | attr_stmt | ID '=' ID | subgraph
+    fn statement(&mut self) -> Result<Vec<String>, DotError> {
+        let token = self.peek().clone();
+
+        // attr_stmt : (graph | node | edge) attr_list
+        if ["graph", "node", "edge"]
+            .iter()
+            .any(|keyword| token.kind.is_keyword(keyword))
+        {
+            self.next();
+
+            if self.peek_kind(0) != &TokenKind::OpenBracket {
+                let next = self.peek().clone();
+
+                return Err(self.error(&next, "`[`"));
+            }
+
+            let attributes = self.attribute_lists()?;
+
+            if token.kind.is_keyword("edge") {
+                self.edge_defaults.extend(attributes);
+            }
+
+            return Ok(Vec::new());
+        }
+
+        // ID '=' ID
+        if matches!(token.kind, TokenKind::Id { .. })
+            && !token.kind.is_keyword("subgraph")
+            && self.peek_kind(1) == &TokenKind::Equals
+        {
+            self.id("a name")?;
+            self.next();
+            self.id("a value")?;
+
+            return Ok(Vec::new());
+        }
+
+        let mut endpoints = vec![self.endpoint()?];
+
+        if !matches!(self.peek_kind(0), TokenKind::EdgeOp { .. }) {
+            // node_stmt : node_id [attr_list], a subgraph on its own has nothing more to it
+            if token.kind != TokenKind::OpenBrace && !token.kind.is_keyword("subgraph") {
+                self.attribute_lists()?;
+            }
+
+            return Ok(endpoints.remove(0));
+        }
+
+        // edge_stmt : (node_id | subgraph) edgeRHS [attr_list]
+        while let TokenKind::EdgeOp { directed } = *self.peek_kind(0) {
+            let op = self.next();
+
+            if directed != self.directed {
+                return Err(DotError {
+                    line: op.line,
+                    column: op.column,
+                    message: if self.directed {
+                        "edges in a digraph use `->`".to_string()
+                    } else {
+                        "edges in a graph use `--`".to_string()
+                    },
+                });
+            }
+
+            endpoints.push(self.endpoint()?);
+        }
+
+        let mut attributes = self.edge_defaults.clone();
+        attributes.extend(self.attribute_lists()?);
+
+        for pair in endpoints.windows(2) {
+            for from in &pair[0] {
+                for to in &pair[1] {
+                    self.graph.edges.push(DotEdge {
+                        from: from.clone(),
+                        to: to.clone(),
+                        attributes: attributes.clone(),
+                        line: token.line,
+                        column: token.column,
+                    });
+                }
+            }
+        }
+
+        Ok(endpoints.concat())
+    }
+
+    // A node id with an optional port, or a subgraph, returns the nodes it stands for
+    fn endpoint(&mut self) -> Result<Vec<String>, DotError> {
+        let token = self.peek().clone();
+
+        // subgraph : [subgraph [ID]] '{' stmt_list '}'
+        if token.kind == TokenKind::OpenBrace || token.kind.is_keyword("subgraph") {
+            if token.kind.is_keyword("subgraph") {
+                self.next();
+
+                if matches!(self.peek_kind(0), TokenKind::Id { .. }) {
+                    self.id("a subgraph name")?;
+                }
+            }
+
+            self.expect(&TokenKind::OpenBrace, "`{`")?;
+
+            // Defaults set inside a subgraph stay inside it
+            let edge_defaults = self.edge_defaults.clone();
+            let nodes = self.statements();
+            self.edge_defaults = edge_defaults;
+
+            return nodes;
+        }
+
+        // node_id : ID [port], port : ':' ID [':' compass_pt]
+        let node = self.id("a node name, a subgraph or `}`")?;
+
+        for _ in 0..2 {
+            if !self.eat(&TokenKind::Colon) {
+                break;
+            }
+
+            self.id("a port name")?;
+        }
+
+        if self.seen.insert(node.clone()) {
+            self.graph.nodes.push(node.clone());
+        }
+
+        Ok(vec![node])
+    }
+
+    // attr_list : '[' [a_list] ']' [attr_list], a_list : ID '=' ID [(';' | ',')] [a_list]
+    fn attribute_lists(&mut self) -> Result<Vec<(String, String)>, DotError> {
+        let mut attributes = Vec::new();
+
+        while self.eat(&TokenKind::OpenBracket) {
+            while !self.eat(&TokenKind::CloseBracket) {
+                let key = self.id("an attribute name or `]`")?;
+                self.expect(&TokenKind::Equals, "`=`")?;
+                let value = self.id("an attribute value")?;
+
+                attributes.push((key, value));
+
+                if !self.eat(&TokenKind::Comma) {
+                    self.eat(&TokenKind::Semicolon);
+                }
+            }
+        }
+
+        Ok(attributes)
+    }
+}
+
+// Keywords can't be used as names unless quoted
+fn is_reserved(value: &str) -> bool {
+    ["node", "edge", "graph", "digraph", "subgraph", "strict"]
+        .iter()
+        .any(|keyword| value.eq_ignore_ascii_case(keyword))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn edges(graph: &DotGraph) -> Vec<(&str, &str, Option<&str>)> {
+        graph
+            .edges
+            .iter()
+            .map(|edge| {
+                (
+                    edge.from.as_str(),
+                    edge.to.as_str(),
+                    edge.attribute("label"),
+                )
+            })
+            .collect()
+    }
+
+    fn error(input: &str) -> String {
+        parse(input).unwrap_err().to_string()
+    }
+
+    #[test]
+    fn parses_nodes_and_edges() {
+        let graph = parse(
+            r#"graph Towns {
+                Lonely;
+                "Ash Burhwich" [shape=box];
+                "Ash Burhwich" -- "Har Leahhampton" [label="10 m / 50 gold", len=1];
+                "Har Leahhampton" -- Lonely [len=2, label="4 m / 20 gold"];
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(graph.nodes, ["Lonely", "Ash Burhwich", "Har Leahhampton"]);
+        assert_eq!(
+            edges(&graph),
+            [
+                ("Ash Burhwich", "Har Leahhampton", Some("10 m / 50 gold")),
+                ("Har Leahhampton", "Lonely", Some("4 m / 20 gold")),
+            ]
+        );
+        assert_eq!(graph.edges[1].attribute("len"), Some("2"));
+    }
+
+    #[test]
+    fn parses_every_kind_of_id() {
+        let graph = parse(
+            r#"graph {
+                plain_name -- "quoted name";
+                <<b>Bold</b> town> -- 42;
+                "Stoke " + "Tunchō" -- -3.5;
+                "The \"Quoted\" Inn" -- Ünïcode;
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            graph.nodes,
+            [
+                "plain_name",
+                "quoted name",
+                "<b>Bold</b> town",
+                "42",
+                "Stoke Tunchō",
+                "-3.5",
+                "The \"Quoted\" Inn",
+                "Ünïcode",
+            ]
+        );
+    }
+
+    #[test]
+    fn later_attributes_win() {
+        let graph =
+            parse(r#"graph { edge [label="1 m / 5 gold"]; a -- b [label="2 m / 10 gold"]; }"#)
+                .unwrap();
+
+        assert_eq!(graph.edges[0].attribute("label"), Some("2 m / 10 gold"));
+    }
+
+    #[test]
+    fn parses_statements_over_several_lines() {
+        let graph = parse(
+            "graph {\n  a\n    --\n  b\n  [\n    label = \"3 m / 15 gold\",\n    len = 1\n  ]\n  \"long \\\n name\" -- c\n}",
+        )
+        .unwrap();
+
+        assert_eq!(
+            edges(&graph),
+            [("a", "b", Some("3 m / 15 gold")), ("long  name", "c", None)]
+        );
+        assert_eq!((graph.edges[1].line, graph.edges[1].column), (9, 3));
+    }
+
+    #[test]
+    fn parses_subgraphs() {
+        let graph = parse(
+            r#"graph {
+                subgraph coast { edge [label="5 m / 25 gold"]; a -- b; }
+                c -- { d e } [label="6 m / 30 gold"];
+                f -- g;
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            edges(&graph),
+            [
+                ("a", "b", Some("5 m / 25 gold")),
+                ("c", "d", Some("6 m / 30 gold")),
+                ("c", "e", Some("6 m / 30 gold")),
+                ("f", "g", None),
+            ]
+        );
+    }
+
+    #[test]
+    fn skips_comments() {
+        let graph = parse(
+            "# generated by hand\ngraph {\n  // a line comment -- x\n  a -- b; /* a block\n  comment -- y */ c;\n    # a preprocessor line\n}",
+        )
+        .unwrap();
+
+        assert_eq!(graph.nodes, ["a", "b", "c"]);
+        assert_eq!(edges(&graph), [("a", "b", None)]);
+    }
+
+    #[test]
+    fn only_starts_a_hash_comment_at_the_start_of_a_line() {
+        assert_eq!(
+            error("graph {\n  d # not a comment here\n}"),
+            "line 2, column 5: unexpected character `#`"
+        );
+    }
+
+    #[test]
+    fn reports_where_malformed_input_is() {
+        assert_eq!(
+            error("graph {\n  a -- b [label=\"1 m / 5 gold\"\n}"),
+            "line 3, column 1: expected an attribute name or `]`, found `}`"
+        );
+        assert_eq!(
+            error("graph {\n  a -> b;\n}"),
+            "line 2, column 5: edges in a graph use `--`"
+        );
+        assert_eq!(
+            error("graph {\n  a -- \"b;\n}"),
+            "line 2, column 8: unterminated string"
+        );
+        assert_eq!(
+            error("graph {\n  /* never closed\n}"),
+            "line 2, column 3: unterminated comment"
+        );
+        assert_eq!(
+            error("graph {\n  a -- b;\n"),
+            "line 3, column 1: expected `}`, found the end of the file"
+        );
+        assert_eq!(
+            error("graph {\n  node -- b;\n}"),
+            "line 2, column 8: expected `[`, found `--`"
+        );
+        assert_eq!(
+            error("graph {\n  a -- \"b\" + c;\n}"),
+            "line 2, column 14: expected a quoted string after `+`, found `c`"
+        );
+        assert_eq!(
+            error("tree { a -- b; }"),
+            "line 1, column 1: expected `graph` or `digraph`, found `tree`"
+        );
+    }
+}
@@ -0,0 +1,944 @@
+use serde::{Deserialize, Serialize};
+use std::time::Instant;
+
+use crate::core::dice::{Dice, DiceRoll, RollMode};
+use crate::entities::character::Attribute;
+use crate::entities::item::ItemKind;
+
+// Const for how many game ticks pass each real second while on the road
+pub const JOURNEY_TICKS_PER_SEC: u32 = 60;
+
+// Consts for encounters, the chance of one on each leg and the dice for what's won and lost
+const ENCOUNTER_CHANCE: u32 = 35;
+const BANDIT_TOLL: &str = "2d10+10";
+const BANDIT_LOOT: &str = "2d10";
+const BANDIT_ROBBERY: &str = "3d10+5";
+const BANDIT_DELAY: &str = "1d20+20";
+const TRAVELLER_REWARD: &str = "1d10+5";
+const TRAVELLER_DELAY: &str = "2d20+20";
+
+// Consts for the difficulty of encounter skill checks
+const FIGHT_DC: i32 = 13;
+const FLEE_DC: i32 = 11;
+const HAGGLE_DC: i32 = 12;
+const GUIDE_DC: i32 = 10;
+
+// Struct for one leg of a journey, between two neighbouring towns
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Leg {
+    pub from: String,
+    pub to: String,
+    pub distance: u32,
+    pub cost: u32,
+}
+
+// Functions for Leg
+impl Leg {
+    // How many ticks the leg takes before any effects
+    pub fn travel_time(&self) -> u32 {
+        self.distance * 10 // 1 mile = 10 ticks
+    }
+}
+
+// Struct for a planned route between two towns, every town along the way and the legs between them
+#[derive(Debug, Clone)]
+pub struct Route {
+    pub towns: Vec<String>,
+    pub legs: Vec<Leg>,
+}
+
+// Functions for Route
+impl Route {
+    pub fn distance(&self) -> u32 {
+        self.legs.iter().map(|leg| leg.distance).sum()
+    }
+
+    // The fare for the whole route, paid leg by leg
+    pub fn fare(&self, effects: &crate::entities::effects::StatusEffects) -> u32 {
+        self.legs
+            .iter()
+            .map(|leg| effects.adjust_fare(leg.cost))
+            .sum()
+    }
+
+    // How many ticks the whole route takes to travel
+    pub fn travel_time(&self, effects: &crate::entities::effects::StatusEffects) -> u32 {
+        self.legs
+            .iter()
+            .map(|leg| effects.adjust_travel_time(leg.travel_time()))
+            .sum()
+    }
+
+    // When the player would arrive setting off now
+    pub fn eta(
+        &self,
+        departure: &super::time::GameTime,
+        effects: &crate::entities::effects::StatusEffects,
+    ) -> Eta {
+        let ticks = self.travel_time(effects);
+        let phase_ticks = super::time::TICKS_PER_DAY / 4;
+
+        // Round part phases up, nobody arrives early
+        let mut days = ticks / super::time::TICKS_PER_DAY;
+        let mut phases = (ticks % super::time::TICKS_PER_DAY).div_ceil(phase_ticks);
+
+        if phases == 4 {
+            days += 1;
+            phases = 0;
+        }
+
+        let mut arrival = departure.clone();
+        arrival.advance(ticks);
+
+        Eta {
+            days,
+            phases,
+            arrival,
+        }
+    }
+}
+
+// Struct for how long a route takes and when it arrives
+pub struct Eta {
+    pub days: u32,
+    pub phases: u32,
+    pub arrival: super::time::GameTime,
+}
+
+// Functions for Eta
+impl Eta {
+    // Describe the ETA for the player, e.g. "1 day and 2 phases, arriving at dusk on day 3"
+    pub fn describe(&self) -> String {
+        let plural = |count: u32, word: &str| {
+            format!("{} {}{}", count, word, if count == 1 { "" } else { "s" })
+        };
+
+        let duration = match (self.days, self.phases) {
+            (0, 0) => "no time at all".to_string(),
+            (0, phases) => plural(phases, "phase"),
+            (days, 0) => plural(days, "day"),
+            (days, phases) => format!("{} and {}", plural(days, "day"), plural(phases, "phase")),
+        };
+
+        let when = match self.arrival.phase {
+            super::time::Phase::Dawn => "at dawn",
+            super::time::Phase::Day => "during the day",
+            super::time::Phase::Dusk => "at dusk",
+            super::time::Phase::Night => "at night",
+        };
+
+        format!(
+            "{}, arriving {} on day {}",
+            duration, when, self.arrival.day
+        )
+    }
+}
+
+// Enum for why there's no route between two towns
+#[derive(Debug)]
+pub enum RouteError {
+    SameTown,
+    Unreachable(String),
+}
+
+impl std::fmt::Display for RouteError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RouteError::SameTown => write!(f, "You're already there."),
+            RouteError::Unreachable(destination) => {
+                write!(f, "There's no road to {} from here.", destination)
+            }
+        }
+    }
+}
+
+impl std::error::Error for RouteError {}
+
+// Struct for a journey in progress, following the shortest route leg by leg
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Journey {
+    pub destination: String,
+    pub legs: Vec<Leg>,
+    pub leg: usize,
+    pub leg_progress: u32,
+    pub leg_length: u32,
+    pub pending_encounter: Option<(u32, Encounter)>,
+    pub encounter: Option<Encounter>,
+    pub stop_early: bool,
+    #[serde(skip)]
+    pub last_update: Option<Instant>,
+}
+
+// Enum for what happened after travelling for a while
+pub enum Progress {
+    Travelling,
+    Encounter,
+    LegComplete,
+}
+
+// Functions for Journey
+impl Journey {
+    // Create a new Journey, the first leg still needs starting
+    pub fn new(destination: String, legs: Vec<Leg>) -> Self {
+        Self {
+            destination,
+            legs,
+            leg: 0,
+            leg_progress: 0,
+            leg_length: 0,
+            pending_encounter: None,
+            encounter: None,
+            stop_early: false,
+            last_update: None,
+        }
+    }
+
+    pub fn current_leg(&self) -> Option<&Leg> {
+        self.legs.get(self.leg)
+    }
+
+    // Set off on the current leg, rolling for whether something happens along the way
+    pub fn start_leg(&mut self, travel_time: u32, dice: &mut Dice) {
+        self.leg_progress = 0;
+        self.leg_length = travel_time.max(1);
+        self.pending_encounter = None;
+
+        if dice.die(100) <= ENCOUNTER_CHANCE {
+            // Somewhere in the middle half of the leg
+            let at = self.leg_length / 4 + dice.die((self.leg_length / 2).max(1));
+
+            self.pending_encounter = Some((at, Encounter::roll(dice)));
+        }
+    }
+
+    // Travel for up to a number of ticks, stopping early for an encounter or the end of the leg
+    // Returns how many ticks were travelled and what happened
+    pub fn travel(&mut self, ticks: u32) -> (u32, Progress) {
+        if self.encounter.is_some() {
+            return (0, Progress::Encounter);
+        }
+
+        let mut limit = self.leg_length.saturating_sub(self.leg_progress);
+
+        if let Some((at, _)) = &self.pending_encounter {
+            limit = limit.min(at.saturating_sub(self.leg_progress));
+        }
+
+        let travelled = ticks.min(limit);
+        self.leg_progress += travelled;
+
+        match self.pending_encounter.take() {
+            Some((at, encounter)) if self.leg_progress >= at => {
+                self.encounter = Some(encounter);
+
+                (travelled, Progress::Encounter)
+            }
+            pending => {
+                self.pending_encounter = pending;
+
+                if self.leg_progress >= self.leg_length {
+                    (travelled, Progress::LegComplete)
+                } else {
+                    (travelled, Progress::Travelling)
+                }
+            }
+        }
+    }
+
+    // How far through the whole journey the player is, from 0 to 1
+    pub fn progress(&self) -> f64 {
+        let total: u32 = self.legs.iter().map(|leg| leg.distance).sum();
+
+        if total == 0 {
+            return 1.0;
+        }
+
+        let completed: u32 = self.legs[..self.leg.min(self.legs.len())]
+            .iter()
+            .map(|leg| leg.distance)
+            .sum();
+
+        let current = self.current_leg().map_or(0.0, |leg| {
+            leg.distance as f64 * self.leg_progress as f64 / self.leg_length.max(1) as f64
+        });
+
+        ((completed as f64 + current) / total as f64).min(1.0)
+    }
+
+    // The fare for the legs that haven't been started yet
+    pub fn remaining_fare(&self, effects: &crate::entities::effects::StatusEffects) -> u32 {
+        self.legs
+            .iter()
+            .skip(self.leg + 1)
+            .map(|leg| effects.adjust_fare(leg.cost))
+            .sum()
+    }
+}
+
+// Enum for things that can happen on the road
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum Encounter {
+    Merchant { item_id: u32, price: u32 },
+    Bandits { toll: u32 },
+    LostTraveller,
+}
+
+// Struct for how an encounter turned out
+pub struct Outcome {
+    pub notice: String,
+    pub delay: u32,
+}
+
+// Functions for Encounter
+impl Encounter {
+    // Roll a random encounter
+    fn roll(dice: &mut Dice) -> Self {
+        match dice.die(3) {
+            1 => {
+                let wares: Vec<_> = crate::entities::item::catalogue()
+                    .into_iter()
+                    .filter(|item| {
+                        matches!(
+                            item.kind,
+                            ItemKind::Food | ItemKind::Potion | ItemKind::Misc
+                        )
+                    })
+                    .collect();
+
+                let item = &wares[dice.die(wares.len() as u32) as usize - 1];
+
+                // Goods on the road cost half as much again as in town
+                Encounter::Merchant {
+                    item_id: item.id,
+                    price: item.value + item.value / 2,
+                }
+            }
+            2 => Encounter::Bandits {
+                toll: roll_notation(dice, BANDIT_TOLL),
+            },
+            _ => Encounter::LostTraveller,
+        }
+    }
+
+    // Describe the encounter for the player
+    pub fn describe(&self) -> String {
+        match self {
+            Encounter::Merchant { item_id, price } => format!(
+                "A travelling merchant waves you down, offering {} for {} gold.",
+                item_name(*item_id),
+                price
+            ),
+            Encounter::Bandits { toll } => format!(
+                "Bandits step out onto the road and demand {} gold to let you pass.",
+                toll
+            ),
+            Encounter::LostTraveller => {
+                "A traveller sits by the roadside, hopelessly lost and asking for help.".into()
+            }
+        }
+    }
+
+    // The choices the player has
+    pub fn options(&self) -> Vec<String> {
+        match self {
+            Encounter::Merchant { item_id, price } => vec![
+                format!("Buy {} ({} gold)", item_name(*item_id), price),
+                "Haggle (Charm)".into(),
+                "Move On".into(),
+            ],
+            Encounter::Bandits { toll } => vec![
+                "Fight (Strength)".into(),
+                "Flee (Agility)".into(),
+                format!("Pay the Toll ({} gold)", toll),
+            ],
+            Encounter::LostTraveller => vec!["Show the Way (Wit)".into(), "Move On".into()],
+        }
+    }
+
+    // Play out the player's choice, returns None if the choice doesn't exist
+    pub fn resolve(
+        &self,
+        choice: usize,
+        player: &mut crate::entities::player::Player,
+        dice: &mut Dice,
+    ) -> Option<Outcome> {
+        // A blessing of fortune gives the player the better of two rolls
+        let mode = if player
+            .effects
+            .has(&crate::entities::effects::EffectKind::Fortune)
+        {
+            RollMode::Advantage
+        } else {
+            RollMode::Normal
+        };
+
+        let outcome = |notice: String| Outcome { notice, delay: 0 };
+
+        match (self, choice) {
+            // Merchant
+            (Encounter::Merchant { item_id, price }, 0) => {
+                Some(outcome(buy(player, *item_id, *price)))
+            }
+            (Encounter::Merchant { item_id, price }, 1) => {
+                let check = dice.skill_check(
+                    &player.character.attributes,
+                    Attribute::Charm,
+                    HAGGLE_DC,
+                    mode,
+                );
+
+                if check.success {
+                    Some(outcome(checked(&check, buy(player, *item_id, price / 2))))
+                } else {
+                    Some(outcome(checked(
+                        &check,
+                        "The merchant takes offence and rides on.".into(),
+                    )))
+                }
+            }
+            (Encounter::Merchant { .. }, 2) => Some(outcome("You wave the merchant on.".into())),
+            // Bandits
+            (Encounter::Bandits { .. }, 0) => {
+                let check = dice.skill_check(
+                    &player.character.attributes,
+                    Attribute::Strength,
+                    FIGHT_DC,
+                    mode,
+                );
+
+                if check.success {
+                    let loot = roll_notation(dice, BANDIT_LOOT);
+                    player.gold += loot;
+
+                    Some(outcome(checked(
+                        &check,
+                        format!("The bandits scatter, leaving {} gold behind.", loot),
+                    )))
+                } else {
+                    Some(Outcome {
+                        notice: checked(&check, robbed(player, dice)),
+                        delay: roll_notation(dice, BANDIT_DELAY),
+                    })
+                }
+            }
+            (Encounter::Bandits { .. }, 1) => {
+                let check = dice.skill_check(
+                    &player.character.attributes,
+                    Attribute::Agility,
+                    FLEE_DC,
+                    mode,
+                );
+
+                if check.success {
+                    Some(outcome(checked(
+                        &check,
+                        "You slip away into the trees.".into(),
+                    )))
+                } else {
+                    Some(outcome(checked(&check, robbed(player, dice))))
+                }
+            }
+            (Encounter::Bandits { toll }, 2) => {
+                let paid = (*toll).min(player.gold);
+                player.gold -= paid;
+
+                Some(outcome(format!(
+                    "You hand over {} gold and the bandits let you pass.",
+                    paid
+                )))
+            }
+            // Lost Traveller
+            (Encounter::LostTraveller, 0) => {
+                let check =
+                    dice.skill_check(&player.character.attributes, Attribute::Wit, GUIDE_DC, mode);
+
+                if check.success {
+                    let reward = roll_notation(dice, TRAVELLER_REWARD);
+                    player.gold += reward;
+
+                    Some(outcome(checked(
+                        &check,
+                        format!(
+                            "You set them on the right road and they press {} gold into your hand.",
+                            reward
+                        ),
+                    )))
+                } else {
+                    Some(Outcome {
+                        notice: checked(
+                            &check,
+                            "You both get turned around before finding the road again.".into(),
+                        ),
+                        delay: roll_notation(dice, TRAVELLER_DELAY),
+                    })
+                }
+            }
+            (Encounter::LostTraveller, 1) => Some(outcome(
+                "You leave the traveller to find their own way.".into(),
+            )),
+            _ => None,
+        }
+    }
+}
+
+// Follow a notice with the skill check that decided it
+fn checked(check: &crate::core::dice::CheckResult, notice: String) -> String {
+    format!("{} ({})", notice, check.describe())
+}
+
+// Buy an item from a travelling merchant
+fn buy(player: &mut crate::entities::player::Player, item_id: u32, price: u32) -> String {
+    let Some(item) = crate::entities::item::Item::from_id(item_id) else {
+        return "The merchant has nothing left to sell.".into();
+    };
+
+    if player.gold < price {
+        return format!("You can't afford the {} gold for {}.", price, item.name);
+    }
+
+    let name = item.name.clone();
+
+    match player.inventory.add(item, 1) {
+        Ok(()) => {
+            player.gold -= price;
+
+            format!("You buy {} for {} gold.", name, price)
+        }
+        Err(e) => format!("{} You leave the {} with the merchant.", e, name),
+    }
+}
+
+// Lose gold to bandits
+fn robbed(player: &mut crate::entities::player::Player, dice: &mut Dice) -> String {
+    let stolen = roll_notation(dice, BANDIT_ROBBERY).min(player.gold);
+    player.gold -= stolen;
+
+    format!("The bandits make off with {} gold.", stolen)
+}
+
+// Roll dice written in notation, a bad notation is logged and rolls nothing
+fn roll_notation(dice: &mut Dice, notation: &str) -> u32 {
+    match notation.parse::<DiceRoll>() {
+        Ok(roll) => dice.roll(roll).total.max(0) as u32,
+        Err(e) => {
+            log::error!("{}", e);
+            0
+        }
+    }
+}
+
+fn item_name(item_id: u32) -> String {
+    crate::entities::item::Item::from_id(item_id)
+        .map(|item| item.name)
+        .unwrap_or_else(|| "wares".into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entities::effects::StatusEffects;
+
+    fn leg(from: &str, to: &str, distance: u32) -> Leg {
+        Leg {
+            from: from.into(),
+            to: to.into(),
+            distance,
+            cost: distance * 5,
+        }
+    }
+
+    fn journey() -> Journey {
+        Journey::new(
+            "Dunmere".into(),
+            vec![
+                leg("Ash Burhwich", "Kelmarsh", 3),
+                leg("Kelmarsh", "Fallow", 5),
+                leg("Fallow", "Dunmere", 2),
+            ],
+        )
+    }
+
+    fn player(gold: u32) -> crate::entities::player::Player {
+        crate::entities::player::Player::new(
+            1,
+            "Ada".into(),
+            "Ash Burhwich".into(),
+            gold,
+            Default::default(),
+        )
+    }
+
+    // Travel a whole journey a few ticks at a time, waving off every encounter, and list what happened
+    fn travel_to_the_end(seed: u64) -> Vec<String> {
+        let mut dice = Dice::new(seed);
+        let mut journey = journey();
+        let mut events = Vec::new();
+        let mut last_progress = 0.0;
+
+        journey.start_leg(journey.legs[0].travel_time(), &mut dice);
+
+        loop {
+            let (travelled, progress) = journey.travel(7);
+
+            assert!(journey.progress() >= last_progress);
+            last_progress = journey.progress();
+
+            match progress {
+                Progress::Travelling => assert_eq!(travelled, 7),
+                Progress::Encounter => {
+                    // Nothing moves until the encounter is dealt with
+                    assert!(matches!(journey.travel(7), (0, Progress::Encounter)));
+
+                    let encounter = journey.encounter.take().unwrap();
+                    events.push(format!("{:?} on leg {}", encounter, journey.leg));
+                }
+                Progress::LegComplete => {
+                    assert_eq!(journey.leg_progress, journey.leg_length);
+                    events.push(format!("arrived at {}", journey.legs[journey.leg].to));
+
+                    journey.leg += 1;
+
+                    let Some(leg) = journey.current_leg() else {
+                        break;
+                    };
+
+                    journey.start_leg(leg.travel_time(), &mut dice);
+                }
+            }
+        }
+
+        assert_eq!(journey.progress(), 1.0);
+
+        events
+    }
+
+    #[test]
+    fn travels_every_leg_to_the_end() {
+        let mut met_someone = false;
+
+        for seed in 0..50 {
+            let events = travel_to_the_end(seed);
+            let arrivals: Vec<&String> = events
+                .iter()
+                .filter(|event| event.starts_with("arrived"))
+                .collect();
+
+            assert_eq!(
+                arrivals,
+                [
+                    "arrived at Kelmarsh",
+                    "arrived at Fallow",
+                    "arrived at Dunmere"
+                ]
+            );
+            assert!(events.len() <= 6);
+            assert_eq!(events, travel_to_the_end(seed));
+
+            met_someone |= events.len() > 3;
+        }
+
+        assert!(met_someone);
+    }
+
+    #[test]
+    fn stops_for_an_encounter_where_it_was_rolled() {
+        let mut journey = journey();
+        journey.leg_length = 30;
+        journey.pending_encounter = Some((12, Encounter::LostTraveller));
+
+        assert!(matches!(journey.travel(10), (10, Progress::Travelling)));
+        assert!(matches!(journey.travel(10), (2, Progress::Encounter)));
+        assert_eq!(journey.leg_progress, 12);
+        assert!(journey.pending_encounter.is_none());
+
+        journey.encounter = None;
+        assert!(matches!(journey.travel(100), (18, Progress::LegComplete)));
+    }
+
+    #[test]
+    fn progress_and_remaining_fare_follow_the_legs() {
+        let effects = StatusEffects::default();
+        let mut journey = journey();
+        journey.leg_length = 30;
+
+        assert_eq!(journey.progress(), 0.0);
+        assert_eq!(journey.remaining_fare(&effects), 25 + 10);
+
+        journey.leg_progress = 15;
+        assert!((journey.progress() - 0.15).abs() < 1e-9);
+
+        journey.leg = 1;
+        journey.leg_progress = 0;
+        assert!((journey.progress() - 0.3).abs() < 1e-9);
+        assert_eq!(journey.remaining_fare(&effects), 10);
+
+        journey.leg = 2;
+        assert_eq!(journey.remaining_fare(&effects), 0);
+
+        journey.leg = 3;
+        assert_eq!(journey.progress(), 1.0);
+    }
+
+    // Resolve a choice for every seed, checking each outcome and that both a pass and a fail came up
+    fn resolve_both_ways(
+        encounter: Encounter,
+        choice: usize,
+        check: impl Fn(bool, &Outcome, u32, &crate::entities::player::Player),
+    ) {
+        let (mut passed, mut failed) = (false, false);
+
+        for seed in 0..100 {
+            let mut player = player(100);
+            let outcome = encounter
+                .resolve(choice, &mut player, &mut Dice::new(seed))
+                .unwrap();
+
+            let success = outcome.notice.contains("passed by");
+            assert!(success || outcome.notice.contains("failed by"));
+            check(success, &outcome, 100, &player);
+
+            // The same seed always plays out the same way
+            let again = encounter
+                .resolve(choice, &mut self::player(100), &mut Dice::new(seed))
+                .unwrap();
+            assert_eq!(again.notice, outcome.notice);
+            assert_eq!(again.delay, outcome.delay);
+
+            passed |= success;
+            failed |= !success;
+        }
+
+        assert!(passed && failed);
+    }
+
+    #[test]
+    fn resolves_a_merchant() {
+        let bread = crate::entities::item::Item::from_id(1).unwrap();
+        let merchant = Encounter::Merchant {
+            item_id: bread.id,
+            price: 30,
+        };
+        let mut dice = Dice::new(1);
+
+        let mut buyer = player(100);
+        let outcome = merchant.resolve(0, &mut buyer, &mut dice).unwrap();
+        assert_eq!(outcome.notice, "You buy Bread for 30 gold.");
+        assert_eq!(buyer.gold, 70);
+
+        let mut broke = player(10);
+        let outcome = merchant.resolve(0, &mut broke, &mut dice).unwrap();
+        assert_eq!(outcome.notice, "You can't afford the 30 gold for Bread.");
+        assert_eq!(broke.gold, 10);
+
+        let mut passer = player(100);
+        let outcome = merchant.resolve(2, &mut passer, &mut dice).unwrap();
+        assert_eq!(outcome.notice, "You wave the merchant on.");
+        assert_eq!(passer.gold, 100);
+
+        resolve_both_ways(merchant.clone(), 1, |success, outcome, before, player| {
+            let paid = if success { 15 } else { 0 };
+
+            assert_eq!(player.gold, before - paid);
+            assert_eq!(outcome.delay, 0);
+        });
+
+        assert!(merchant.resolve(3, &mut player(100), &mut dice).is_none());
+    }
+
+    #[test]
+    fn resolves_bandits() {
+        let bandits = Encounter::Bandits { toll: 25 };
+
+        resolve_both_ways(bandits.clone(), 0, |success, outcome, before, player| {
+            if success {
+                assert!((before + 2..=before + 20).contains(&player.gold));
+                assert_eq!(outcome.delay, 0);
+            } else {
+                assert!((before - 35..=before - 8).contains(&player.gold));
+                assert!((21..=40).contains(&outcome.delay));
+            }
+        });
+
+        resolve_both_ways(bandits.clone(), 1, |success, outcome, before, player| {
+            if success {
+                assert_eq!(player.gold, before);
+            } else {
+                assert!((before - 35..=before - 8).contains(&player.gold));
+            }
+            assert_eq!(outcome.delay, 0);
+        });
+
+        let mut payer = player(100);
+        bandits.resolve(2, &mut payer, &mut Dice::new(1)).unwrap();
+        assert_eq!(payer.gold, 75);
+
+        // The toll can't take more than the player has
+        let mut broke = player(10);
+        let outcome = bandits.resolve(2, &mut broke, &mut Dice::new(1)).unwrap();
+        assert_eq!(
+            outcome.notice,
+            "You hand over 10 gold and the bandits let you pass."
+        );
+        assert_eq!(broke.gold, 0);
+    }
+
+    #[test]
+    fn resolves_a_lost_traveller() {
+        resolve_both_ways(
+            Encounter::LostTraveller,
+            0,
+            |success, outcome, before, player| {
+                if success {
+                    assert!((before + 6..=before + 15).contains(&player.gold));
+                    assert_eq!(outcome.delay, 0);
+                } else {
+                    assert_eq!(player.gold, before);
+                    assert!((22..=60).contains(&outcome.delay));
+                }
+            },
+        );
+
+        let mut passer = player(100);
+        let outcome = Encounter::LostTraveller
+            .resolve(1, &mut passer, &mut Dice::new(1))
+            .unwrap();
+        assert_eq!(
+            outcome.notice,
+            "You leave the traveller to find their own way."
+        );
+        assert_eq!(passer.gold, 100);
+    }
+
+    #[test]
+    fn fortune_rolls_checks_with_advantage() {
+        let mut player = player(100);
+        player.effects.apply(
+            crate::entities::effects::EffectKind::Fortune,
+            100,
+            &crate::world::time::GameTime::new(),
+        );
+
+        let outcome = Encounter::LostTraveller
+            .resolve(0, &mut player, &mut Dice::new(1))
+            .unwrap();
+
+        assert!(outcome.notice.contains("Wit check with advantage"));
+    }
+
+    fn bundled_world() -> super::super::manager::WorldManager {
+        let mut world_manager = super::super::manager::WorldManager::new();
+        world_manager.load_world().unwrap();
+
+        world_manager
+    }
+
+    fn route(world_manager: &super::super::manager::WorldManager, from: &str, to: &str) -> Route {
+        world_manager
+            .plan_route(&from.to_string(), &to.to_string())
+            .unwrap()
+    }
+
+    #[test]
+    fn plans_the_shortest_route_with_its_fare_and_eta() {
+        let world_manager = bundled_world();
+        let route = route(&world_manager, "Higashi Burhdō", "Kami Byford");
+        let effects = StatusEffects::default();
+
+        assert_eq!(
+            route.towns,
+            [
+                "Higashi Burhdō",
+                "Har Leahhampton",
+                "Ash Burhwich",
+                "Kami Byford"
+            ]
+        );
+        assert_eq!(
+            route.legs.iter().map(|leg| leg.cost).collect::<Vec<u32>>(),
+            [110, 50, 235]
+        );
+        assert_eq!(route.distance(), 22 + 10 + 47);
+        assert_eq!(route.fare(&effects), 395);
+        assert_eq!(route.travel_time(&effects), 790);
+
+        // 790 ticks is most of a day, part phases round up to a whole day
+        let eta = route.eta(&crate::world::time::GameTime::new(), &effects);
+        assert_eq!((eta.days, eta.phases), (1, 0));
+        assert_eq!((eta.arrival.day, eta.arrival.tick), (1, 790));
+        assert_eq!(eta.describe(), "1 day, arriving at night on day 1");
+
+        // Routes are the same both ways
+        let back = self::route(&world_manager, "Kami Byford", "Higashi Burhdō");
+        assert_eq!(back.distance(), route.distance());
+        assert_eq!(back.fare(&effects), route.fare(&effects));
+    }
+
+    #[test]
+    fn effects_change_the_fare_and_eta_leg_by_leg() {
+        let world_manager = bundled_world();
+        let route = route(&world_manager, "Higashi Burhdō", "Kami Byford");
+        let start = crate::world::time::GameTime::new();
+
+        let mut effects = StatusEffects::default();
+        effects.apply(
+            crate::entities::effects::EffectKind::Wayfarer,
+            10_000,
+            &start,
+        );
+        effects.apply(
+            crate::entities::effects::EffectKind::Chilled,
+            10_000,
+            &start,
+        );
+
+        // Each leg is rounded on its own: 82.5, 37.5 and 176.25 gold, 275, 125 and 587.5 ticks
+        assert_eq!(route.fare(&effects), 83 + 38 + 176);
+        assert_eq!(route.travel_time(&effects), 275 + 125 + 588);
+
+        let eta = route.eta(&start, &effects);
+        assert_eq!((eta.days, eta.phases), (1, 1));
+        assert_eq!(
+            eta.describe(),
+            "1 day and 1 phase, arriving at dawn on day 2"
+        );
+    }
+
+    #[test]
+    fn describes_etas_shorter_than_a_day() {
+        let eta = |distance: u32, departure_tick: u32| {
+            let route = Route {
+                towns: Vec::new(),
+                legs: vec![leg("A", "B", distance)],
+            };
+            let mut departure = crate::world::time::GameTime::new();
+            departure.advance(departure_tick);
+
+            route.eta(&departure, &StatusEffects::default()).describe()
+        };
+
+        assert_eq!(eta(0, 0), "no time at all, arriving at dawn on day 1");
+        assert_eq!(eta(1, 0), "1 phase, arriving at dawn on day 1");
+        assert_eq!(eta(45, 0), "2 phases, arriving during the day on day 1");
+        assert_eq!(eta(45, 600), "2 phases, arriving at dawn on day 2");
+        assert_eq!(eta(180, 0), "2 days, arriving at dawn on day 3");
+    }
+
+    #[test]
+    fn explains_why_there_is_no_route() {
+        let world_manager = bundled_world();
+        let town = "Ash Burhwich".to_string();
+
+        let same_town = world_manager.plan_route(&town, &town).unwrap_err();
+        assert!(matches!(same_town, RouteError::SameTown));
+        assert_eq!(same_town.to_string(), "You're already there.");
+
+        let nowhere = world_manager
+            .plan_route(&town, &"Atlantis".to_string())
+            .unwrap_err();
+        assert!(matches!(&nowhere, RouteError::Unreachable(town) if town == "Atlantis"));
+        assert_eq!(
+            nowhere.to_string(),
+            "There's no road to Atlantis from here."
+        );
+    }
+}
@@ -0,0 +1,170 @@
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+use super::manager::{BuildingType, ContainerType};
+use crate::entities::inventory::ItemStack;
+
+// Salt for seeding each container's loot, shops and character creation have their own
+const CONTAINER_SALT: u64 = 0x9E37_79B9_7F4A_7C15;
+
+// Struct for a single entry in a loot table
+struct LootEntry {
+    item_id: u32,
+    weight: u32,
+    min_quantity: u32,
+    max_quantity: u32,
+}
+
+// Shorthand for building loot tables
+const fn entry(item_id: u32, weight: u32, min_quantity: u32, max_quantity: u32) -> LootEntry {
+    LootEntry {
+        item_id,
+        weight,
+        min_quantity,
+        max_quantity,
+    }
+}
+
+// Consts for loot tables by container type
+const BARREL_LOOT: [LootEntry; 4] = [
+    entry(4, 5, 1, 3),   // Ale
+    entry(3, 4, 2, 6),   // Apple
+    entry(1, 2, 1, 2),   // Bread
+    entry(51, 1, 5, 15), // Iron Nails
+];
+const CRATE_LOOT: [LootEntry; 6] = [
+    entry(50, 4, 1, 4),  // Cloth
+    entry(51, 4, 5, 20), // Iron Nails
+    entry(6, 3, 1, 3),   // Torch
+    entry(7, 2, 1, 1),   // Rope
+    entry(8, 3, 2, 6),   // Candle
+    entry(22, 1, 1, 1),  // Hand Axe
+];
+const CHEST_LOOT: [LootEntry; 6] = [
+    entry(62, 5, 1, 6), // Old Coin
+    entry(60, 2, 1, 1), // Silver Ring
+    entry(61, 1, 1, 2), // Gemstone
+    entry(21, 1, 1, 1), // Shortsword
+    entry(31, 1, 1, 1), // Padded Jerkin
+    entry(40, 2, 1, 2), // Healing Draught
+];
+
+// Consts for extra loot by building type, mixed in with the container's table
+const RESIDENCE_LOOT: [LootEntry; 3] = [
+    entry(2, 3, 1, 2),  // Cheese
+    entry(30, 1, 1, 1), // Leather Cap
+    entry(5, 1, 1, 1),  // Waterskin
+];
+const SHOP_LOOT: [LootEntry; 3] = [
+    entry(50, 3, 2, 6), // Cloth
+    entry(62, 2, 2, 8), // Old Coin
+    entry(20, 1, 1, 1), // Dagger
+];
+const TAVERN_LOOT: [LootEntry; 3] = [
+    entry(4, 5, 2, 5), // Ale
+    entry(2, 3, 1, 3), // Cheese
+    entry(1, 3, 1, 3), // Bread
+];
+const TEMPLE_LOOT: [LootEntry; 3] = [
+    entry(8, 5, 3, 10), // Candle
+    entry(52, 4, 2, 6), // Herbs
+    entry(41, 1, 1, 1), // Tonic of Vigour
+];
+
+// Generate the contents of a container, always the same for the same container and world seed
+pub fn generate(
+    container_id: u32,
+    container_type: &ContainerType,
+    building_type: &BuildingType,
+    world_seed: u64,
+) -> Vec<ItemStack> {
+    let mut rng =
+        StdRng::seed_from_u64(world_seed ^ (container_id as u64).wrapping_mul(CONTAINER_SALT));
+
+    let (container_table, rolls, empty_chance): (&[LootEntry], (u32, u32), u32) =
+        match container_type {
+            ContainerType::Barrel => (&BARREL_LOOT, (1, 2), 30),
+            ContainerType::Crate => (&CRATE_LOOT, (1, 3), 25),
+            ContainerType::Chest => (&CHEST_LOOT, (2, 4), 10),
+        };
+
+    let building_table: &[LootEntry] = match building_type {
+        BuildingType::Residence => &RESIDENCE_LOOT,
+        BuildingType::Shop => &SHOP_LOOT,
+        BuildingType::Tavern => &TAVERN_LOOT,
+        BuildingType::Temple => &TEMPLE_LOOT,
+    };
+
+    // Some containers have nothing in them at all
+    if rng.random_range(1..=100) <= empty_chance {
+        return Vec::new();
+    }
+
+    let table: Vec<&LootEntry> = container_table.iter().chain(building_table).collect();
+    let total_weight: u32 = table.iter().map(|entry| entry.weight).sum();
+
+    let mut contents: Vec<ItemStack> = Vec::new();
+
+    for _ in 0..rng.random_range(rolls.0..=rolls.1) {
+        // Weighted pick from the combined table
+        let mut pick = rng.random_range(0..total_weight);
+        let Some(entry) = table.iter().find(|entry| {
+            if pick < entry.weight {
+                true
+            } else {
+                pick -= entry.weight;
+                false
+            }
+        }) else {
+            continue;
+        };
+
+        let Some(item) = crate::entities::item::Item::from_id(entry.item_id) else {
+            log::error!("Failed to find loot item: {}", entry.item_id);
+            continue;
+        };
+
+        let quantity = rng
+            .random_range(entry.min_quantity..=entry.max_quantity)
+            .min(item.max_stack);
+
+        // Merge repeat picks of the same item where the stack allows
+        match contents
+            .iter_mut()
+            .find(|stack| stack.item.id == item.id && stack.quantity + quantity <= item.max_stack)
+        {
+            Some(stack) => stack.quantity += quantity,
+            None => contents.push(ItemStack { item, quantity }),
+        }
+    }
+
+    contents
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_loot_table_entry_is_a_real_item() {
+        let tables: [&[LootEntry]; 7] = [
+            &BARREL_LOOT,
+            &CRATE_LOOT,
+            &CHEST_LOOT,
+            &RESIDENCE_LOOT,
+            &SHOP_LOOT,
+            &TAVERN_LOOT,
+            &TEMPLE_LOOT,
+        ];
+
+        for entry in tables.into_iter().flatten() {
+            assert!(
+                crate::entities::item::Item::from_id(entry.item_id).is_some(),
+                "no item with id {}",
+                entry.item_id
+            );
+            assert!(entry.weight > 0);
+            assert!(entry.min_quantity >= 1 && entry.min_quantity <= entry.max_quantity);
+        }
+    }
+}
@@ -1,10 +1,18 @@
-use petgraph::algo::dijkstra;
+use petgraph::algo::astar;
 use petgraph::graph::NodeIndex;
+use petgraph::visit::EdgeRef;
 use petgraph::{Graph, Undirected};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use strum_macros::EnumIter;
+
+// Consts for where worlds are loaded from, the bundled world and any generated ones
+pub const DEFAULT_WORLD_DIR: &str = "assets";
+const WORLDS_DIR: &str = "worlds";
 
 // Struct for World Manager
 pub struct WorldManager {
@@ -12,6 +20,20 @@ pub struct WorldManager {
     pub world: Option<World>,
     world_graph: Option<Graph<String, JourneyInfo, Undirected>>,
     node_indices: HashMap<String, NodeIndex>,
+    pub current_building_id: Option<u32>,
+    pub current_room_id: Option<u32>,
+    pub current_container_id: Option<u32>,
+    pub world_seed: u64,
+    pub container_contents: HashMap<u32, Vec<crate::entities::inventory::ItemStack>>,
+    pub shop_stock: HashMap<u32, super::shop::ShopStock>,
+    pub world_dir: PathBuf,
+    pub world_identity: Option<WorldIdentity>,
+    pub world_chosen: bool,
+    pub available_worlds: Vec<WorldInfo>,
+    pub character_creation: crate::entities::character::CharacterCreation,
+    pub dice: crate::core::dice::Dice,
+    pub journey: Option<super::journey::Journey>,
+    pub map: Option<super::map::WorldMap>,
 }
 
 // Functions for World Manager
@@ -23,6 +45,20 @@ impl WorldManager {
             world: None,
             world_graph: None,
             node_indices: HashMap::new(),
+            current_building_id: None,
+            current_room_id: None,
+            current_container_id: None,
+            world_seed: 0,
+            container_contents: HashMap::new(),
+            shop_stock: HashMap::new(),
+            world_dir: PathBuf::from(DEFAULT_WORLD_DIR),
+            world_identity: None,
+            world_chosen: false,
+            available_worlds: Vec::new(),
+            character_creation: crate::entities::character::CharacterCreation::new(0),
+            dice: crate::core::dice::Dice::from_entropy(),
+            journey: None,
+            map: None,
         }
     }
 
@@ -32,96 +68,430 @@ impl WorldManager {
         self.world = None;
         self.world_graph = None;
         self.node_indices = HashMap::new();
+        self.current_building_id = None;
+        self.current_room_id = None;
+        self.current_container_id = None;
+        self.world_seed = 0;
+        self.container_contents = HashMap::new();
+        self.shop_stock = HashMap::new();
+        self.world_identity = None;
+        self.journey = None;
+        self.map = None;
+    }
+
+    // Use this world for every game, skipping world selection
+    pub fn choose_world(&mut self, world_dir: PathBuf) {
+        self.world_dir = world_dir;
+        self.world_chosen = true;
     }
 
-    // Load in world JSON and DOT files
+    // Find every world that can be played, the bundled one first and then any in the worlds directory
+    pub fn scan_worlds(&mut self) {
+        self.available_worlds.clear();
+
+        let mut world_dirs = vec![PathBuf::from(DEFAULT_WORLD_DIR)];
+
+        match fs::read_dir(WORLDS_DIR) {
+            Ok(entries) => {
+                let mut generated: Vec<PathBuf> = entries
+                    .flatten()
+                    .map(|entry| entry.path())
+                    .filter(|path| path.is_dir())
+                    .collect();
+                generated.sort();
+
+                world_dirs.extend(generated);
+            }
+            Err(e) => {
+                if e.kind() != std::io::ErrorKind::NotFound {
+                    log::error!("Failed to read worlds directory: {}", e);
+                }
+            }
+        }
+
+        for world_dir in world_dirs {
+            match WorldInfo::from_dir(&world_dir) {
+                Ok(world_info) => self.available_worlds.push(world_info),
+                Err(e) => log::error!("Skipping world in {}: {}", world_dir.display(), e),
+            }
+        }
+    }
+
+    // Load in world JSON and DOT files from the world directory
     pub fn load_world(&mut self) -> Result<(), Box<dyn std::error::Error>> {
-        let world_path = PathBuf::from("assets").join("world.json");
+        let world_path = self.world_dir.join("world.json");
         let world_data = fs::read_to_string(world_path)?;
         let world: World = serde_json::from_str(&world_data)?;
         self.world = Some(world);
 
-        let world_graph_path = PathBuf::from("assets").join("world.dot");
+        let world_graph_path = self.world_dir.join("world.dot");
         let world_graph_data = fs::read_to_string(world_graph_path)?;
+
+        self.world_identity = Some(WorldIdentity::from_data(
+            &self.world_dir,
+            &world_data,
+            &world_graph_data,
+        ));
+
+        let dot_graph = super::dot::parse(&world_graph_data)
+            .map_err(|e| format!("Invalid world.dot, {}", e))?;
+
+        let town_names = self.town_names();
+
         self.world_graph = Some(Graph::new_undirected());
 
         match self.world_graph.as_mut() {
             Some(world_graph) => {
-                for line in world_graph_data.lines() {
-                    if let Some((source, target, label)) = parse_edge_line(line) {
-                        let src_index = *self
-                            .node_indices
-                            .entry(source.clone())
-                            .or_insert_with(|| world_graph.add_node(source.clone()));
-                        let tgt_index = *self
-                            .node_indices
-                            .entry(target.clone())
-                            .or_insert_with(|| world_graph.add_node(target.clone()));
-
-                        if let Some(journey_info) = JourneyInfo::from_label(&label) {
-                            world_graph.add_edge(src_index, tgt_index, journey_info);
-                        }
-                    }
+                // Every town gets a node, even ones with no roads or missing from the DOT file
+                for town in dot_graph.nodes.iter().chain(&town_names) {
+                    self.node_indices
+                        .entry(town.clone())
+                        .or_insert_with(|| world_graph.add_node(town.clone()));
+                }
+
+                for edge in &dot_graph.edges {
+                    let journey_info = edge
+                        .attribute("label")
+                        .and_then(JourneyInfo::from_label)
+                        .ok_or_else(|| {
+                            format!(
+                                "Invalid world.dot, {}",
+                                super::dot::DotError {
+                                    line: edge.line,
+                                    column: edge.column,
+                                    message: format!(
+                                        "the road from {} to {} needs a label like \"22 m / 110 gold\"",
+                                        edge.from, edge.to
+                                    ),
+                                }
+                            )
+                        })?;
+
+                    world_graph.add_edge(
+                        self.node_indices[&edge.from],
+                        self.node_indices[&edge.to],
+                        journey_info,
+                    );
                 }
             }
             None => return Err("Failed to create world graph.".into()),
         }
 
+        // Lay out the map once, every town in the graph whether or not a road reaches it
+        if let Some(world_graph) = self.world_graph.as_ref() {
+            let roads = world_graph
+                .edge_references()
+                .map(|edge| super::map::Road {
+                    from: world_graph[edge.source()].clone(),
+                    to: world_graph[edge.target()].clone(),
+                    distance: edge.weight().distance,
+                })
+                .collect();
+            let towns = world_graph.node_weights().cloned().collect();
+
+            self.map = Some(super::map::WorldMap::new(towns, roads));
+        }
+
         Ok(())
     }
 
-    pub fn get_travel_time(&self, origin: &String, destination: &String) -> u32 {
-        if let (Some(&origin_unwrapped), Some(&destination_unwrapped)) = (
-            self.node_indices.get(origin),
-            self.node_indices.get(destination),
-        ) {
-            if let Some(graph) = self.world_graph.as_ref() {
-                let path = dijkstra(graph, origin_unwrapped, Some(destination_unwrapped), |e| {
-                    e.weight().distance
-                });
-
-                if let Some(cost) = path.get(&destination_unwrapped) {
-                    cost * 10 // 1 mile = 10 ticks
-                } else {
-                    0
-                }
-            } else {
-                0
+    // Get the town the player is currently in
+    pub fn current_town(&self) -> Option<&Town> {
+        let player = self.player.as_ref()?;
+
+        self.town_by_name(&player.town_name)
+    }
+
+    // Get a town by name
+    pub fn town_by_name(&self, town_name: &str) -> Option<&Town> {
+        let world = self.world.as_ref()?;
+
+        world.towns.values().find(|town| town.name == town_name)
+    }
+
+    // Get the names of every town in the world, sorted
+    pub fn town_names(&self) -> Vec<String> {
+        let mut town_names: Vec<String> = self
+            .world
+            .as_ref()
+            .map(|world| world.towns.values().map(|town| town.name.clone()).collect())
+            .unwrap_or_default();
+
+        town_names.sort();
+
+        town_names
+    }
+
+    // Pick a starting town at random, seeded from the world seed so the same game always picks the same town
+    pub fn random_town(&self) -> Option<String> {
+        let town_names = self.town_names();
+
+        if town_names.is_empty() {
+            return None;
+        }
+
+        // Salted so the pick doesn't share a stream with the dice or the player id
+        let mut rng = StdRng::seed_from_u64(self.world_seed ^ 0x2545_F491_4F6C_DD1D);
+
+        Some(town_names[rng.random_range(0..town_names.len())].clone())
+    }
+
+    // Get a new player id, never zero and never the same as an NPC's
+    pub fn new_player_id(&self) -> u32 {
+        let mut rng = StdRng::seed_from_u64(self.world_seed ^ 0x9E37_79B9_7F4A_7C15);
+
+        loop {
+            let id = rng.random_range(1..=u32::MAX);
+
+            if self
+                .world
+                .as_ref()
+                .is_none_or(|world| !world.npcs.contains_key(&id))
+            {
+                return id;
             }
-        } else {
-            0
         }
     }
+
+    // Get the building the player is currently exploring
+    pub fn current_building(&self) -> Option<&Building> {
+        let building_id = self.current_building_id?;
+
+        self.current_town()?
+            .buildings
+            .iter()
+            .find(|building| building.id == building_id)
+    }
+
+    // Get the room the player is currently exploring
+    pub fn current_room(&self) -> Option<&Room> {
+        let room_id = self.current_room_id?;
+
+        self.current_building()?
+            .rooms
+            .iter()
+            .find(|room| room.id == room_id)
+    }
+
+    // Get the container the player is currently searching
+    pub fn current_container(&self) -> Option<&Container> {
+        let container_id = self.current_container_id?;
+
+        self.current_room()?
+            .containers
+            .iter()
+            .find(|container| container.id == container_id)
+    }
+
+    // Open the current container, generating its contents the first time it's opened
+    pub fn open_current_container(
+        &mut self,
+    ) -> Option<&mut Vec<crate::entities::inventory::ItemStack>> {
+        let container = self.current_container()?.clone();
+        let building_type = self.current_building()?.building_type.clone();
+        let world_seed = self.world_seed;
+
+        Some(
+            self.container_contents
+                .entry(container.id)
+                .or_insert_with(|| {
+                    super::loot::generate(
+                        container.id,
+                        &container.container_type,
+                        &building_type,
+                        world_seed,
+                    )
+                }),
+        )
+    }
+
+    // Open the current building's shop, restocking it if it's due
+    pub fn open_current_shop(&mut self, day: u32) -> Option<&mut super::shop::ShopStock> {
+        let building_id = self.current_building()?.id;
+        let world_seed = self.world_seed;
+
+        let stock = self
+            .shop_stock
+            .entry(building_id)
+            .or_insert_with(|| super::shop::ShopStock::generate(building_id, world_seed, day));
+
+        if stock.needs_restock(day) {
+            *stock = super::shop::ShopStock::generate(building_id, world_seed, day);
+        }
+
+        Some(stock)
+    }
+
+    // Get the names of every town the player can travel to, sorted by name
+    pub fn travel_destinations(&self) -> Vec<String> {
+        let current_town = self.player.as_ref().map(|player| player.town_name.as_str());
+
+        let mut destinations: Vec<String> = self
+            .world
+            .as_ref()
+            .map(|world| {
+                world
+                    .towns
+                    .values()
+                    .map(|town| town.name.clone())
+                    .filter(|name| Some(name.as_str()) != current_town)
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        destinations.sort();
+
+        destinations
+    }
+
+    // Get the towns directly connected to a town by road, with the distance in miles and cost in gold
+    pub fn neighbours(&self, town_name: &String) -> Vec<(String, u32, u32)> {
+        let (Some(&town_index), Some(graph)) =
+            (self.node_indices.get(town_name), self.world_graph.as_ref())
+        else {
+            return Vec::new();
+        };
+
+        graph
+            .edges(town_index)
+            .map(|edge| {
+                let neighbour = if edge.source() == town_index {
+                    edge.target()
+                } else {
+                    edge.source()
+                };
+
+                (
+                    graph[neighbour].clone(),
+                    edge.weight().distance,
+                    edge.weight().cost,
+                )
+            })
+            .collect()
+    }
+
+    // Plan the shortest route between two towns
+    pub fn plan_route(
+        &self,
+        origin: &String,
+        destination: &String,
+    ) -> Result<super::journey::Route, super::journey::RouteError> {
+        if origin == destination {
+            return Err(super::journey::RouteError::SameTown);
+        }
+
+        let unreachable = || super::journey::RouteError::Unreachable(destination.clone());
+
+        // Every town is in the graph, so only a name that isn't a town misses here
+        let (Some(&origin_index), Some(&destination_index), Some(graph)) = (
+            self.node_indices.get(origin),
+            self.node_indices.get(destination),
+            self.world_graph.as_ref(),
+        ) else {
+            return Err(unreachable());
+        };
+
+        let (_, path) = astar(
+            graph,
+            origin_index,
+            |node| node == destination_index,
+            |edge| edge.weight().distance,
+            |_| 0,
+        )
+        .ok_or_else(unreachable)?;
+
+        let legs = path
+            .windows(2)
+            .map(|pair| {
+                let edge = graph.find_edge(pair[0], pair[1])?;
+
+                Some(super::journey::Leg {
+                    from: graph[pair[0]].clone(),
+                    to: graph[pair[1]].clone(),
+                    distance: graph[edge].distance,
+                    cost: graph[edge].cost,
+                })
+            })
+            .collect::<Option<Vec<_>>>()
+            .ok_or_else(unreachable)?;
+
+        Ok(super::journey::Route {
+            towns: path.iter().map(|&node| graph[node].clone()).collect(),
+            legs,
+        })
+    }
 }
 
-// Parses an edge line from the DOT file and extracts (town1, town2, label).
-fn parse_edge_line(line: &str) -> Option<(String, String, String)> {
-    let line = line.trim();
+// Struct for a world that can be chosen at New Game
+pub struct WorldInfo {
+    pub name: String,
+    pub dir: PathBuf,
+    pub towns: usize,
+    pub buildings: usize,
+    pub npcs: usize,
+}
 
-    if line.starts_with('"') && line.contains("--") && line.contains("[label=") {
-        let parts: Vec<&str> = line.split("--").collect();
-        if parts.len() == 2 {
-            // Trim and remove quotes from the source town
-            let source = parts[0].trim().trim_matches('"').to_string();
+// Functions for World Info
+impl WorldInfo {
+    // Read a world's details from its directory, it must have both world files
+    fn from_dir(world_dir: &Path) -> Result<Self, Box<dyn std::error::Error>> {
+        if !world_dir.join("world.dot").is_file() {
+            return Err("Missing world.dot".into());
+        }
+
+        let world_data = fs::read_to_string(world_dir.join("world.json"))?;
+        let world: World = serde_json::from_str(&world_data)?;
+
+        super::dot::parse(&fs::read_to_string(world_dir.join("world.dot"))?)
+            .map_err(|e| format!("Invalid world.dot, {}", e))?;
+
+        let name = if world_dir == Path::new(DEFAULT_WORLD_DIR) {
+            "Default World".to_string()
+        } else {
+            world_dir
+                .file_name()
+                .map(|name| name.to_string_lossy().to_string())
+                .unwrap_or_else(|| world_dir.to_string_lossy().to_string())
+        };
+
+        Ok(Self {
+            name,
+            dir: world_dir.to_path_buf(),
+            towns: world.towns.len(),
+            buildings: world.buildings.len(),
+            npcs: world.npcs.len(),
+        })
+    }
+}
+
+// Struct for identifying which world a save was made against
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WorldIdentity {
+    pub path: String,
+    pub hash: String,
+}
 
-            let target_and_label = parts[1].trim();
+// Functions for World Identity
+impl WorldIdentity {
+    // Identify the world in a directory by hashing its files
+    pub fn from_dir(world_dir: &Path) -> Result<Self, Box<dyn std::error::Error>> {
+        let world_data = fs::read_to_string(world_dir.join("world.json"))?;
+        let world_graph_data = fs::read_to_string(world_dir.join("world.dot"))?;
 
-            // Find where the target ends (right before the `[`)
-            let target_end = target_and_label.find('[')?;
-            let target = target_and_label[..target_end]
-                .trim()
-                .trim_matches('"')
-                .to_string();
+        Ok(Self::from_data(world_dir, &world_data, &world_graph_data))
+    }
 
-            // Extract the label content within quotes
-            let label_start = target_and_label.find("label=\"")? + 7;
-            let label_end = target_and_label[label_start..].find('"')? + label_start;
-            let label = target_and_label[label_start..label_end].trim().to_string();
+    // Identify a world from the contents of its files
+    fn from_data(world_dir: &Path, world_data: &str, world_graph_data: &str) -> Self {
+        let mut bytes = world_data.as_bytes().to_vec();
+        bytes.push(0);
+        bytes.extend_from_slice(world_graph_data.as_bytes());
 
-            return Some((source, target, label));
+        Self {
+            path: world_dir.to_string_lossy().to_string(),
+            hash: crate::core::hash::fnv1a(&bytes),
         }
     }
-    None
 }
 
 // Struct for storing distance and cost between towns
@@ -164,9 +534,39 @@ pub struct Town {
     buildings: Vec<Building>,
 }
 
+// Functions for Town
+impl Town {
+    pub fn id(&self) -> u32 {
+        self.id
+    }
+
+    pub fn buildings(&self) -> &[Building] {
+        &self.buildings
+    }
+
+    // Get the building on a cell of the town's street grid
+    pub fn building_at(&self, coords: (u32, u32)) -> Option<&Building> {
+        self.buildings
+            .iter()
+            .find(|building| building.coords == coords)
+    }
+
+    // Get the width and height of the town's street grid, big enough to hold every building
+    pub fn grid_size(&self) -> (u32, u32) {
+        self.buildings
+            .iter()
+            .fold((1, 1), |(width, height), building| {
+                (
+                    width.max(building.coords.0 + 1),
+                    height.max(building.coords.1 + 1),
+                )
+            })
+    }
+}
+
 // Struct for representing a building
 #[derive(Serialize, Deserialize, Debug, Clone)]
-struct Building {
+pub struct Building {
     id: u32,
     name: String,
     building_type: BuildingType,
@@ -175,18 +575,59 @@ struct Building {
     rooms: Vec<Room>,
 }
 
+// Functions for Building
+impl Building {
+    pub fn id(&self) -> u32 {
+        self.id
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn building_type(&self) -> &BuildingType {
+        &self.building_type
+    }
+
+    pub fn coords(&self) -> (u32, u32) {
+        self.coords
+    }
+
+    pub fn rooms(&self) -> &[Room] {
+        &self.rooms
+    }
+
+    // Get the NPC who runs the building, the first one found in its rooms
+    pub fn owner(&self) -> Option<&Npc> {
+        self.rooms.iter().flat_map(|room| room.npcs.iter()).next()
+    }
+}
+
 // Enum for building types
 #[derive(Serialize, Deserialize, Debug, Clone)]
-enum BuildingType {
+pub enum BuildingType {
     Residence,
     Shop,
     Tavern,
     Temple,
 }
 
+// Functions for Building Type
+impl BuildingType {
+    // The character used to draw the building on the town map
+    pub fn glyph(&self) -> char {
+        match self {
+            BuildingType::Residence => 'h',
+            BuildingType::Shop => '$',
+            BuildingType::Tavern => 'T',
+            BuildingType::Temple => '+',
+        }
+    }
+}
+
 // Struct for representing a room
 #[derive(Serialize, Deserialize, Debug, Clone)]
-struct Room {
+pub struct Room {
     id: u32,
     town_id: u32,
     building_id: u32,
@@ -194,9 +635,24 @@ struct Room {
     containers: Vec<Container>,
 }
 
+// Functions for Room
+impl Room {
+    pub fn id(&self) -> u32 {
+        self.id
+    }
+
+    pub fn npcs(&self) -> &[Npc] {
+        &self.npcs
+    }
+
+    pub fn containers(&self) -> &[Container] {
+        &self.containers
+    }
+}
+
 // Struct for representing an NPC
 #[derive(Serialize, Deserialize, Debug, Clone)]
-struct Npc {
+pub struct Npc {
     id: u32,
     name: String,
     sex: NpcSex,
@@ -206,24 +662,45 @@ struct Npc {
     room_id: Option<u32>,
 }
 
+// Functions for NPC
+impl Npc {
+    pub fn id(&self) -> u32 {
+        self.id
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn sex(&self) -> &NpcSex {
+        &self.sex
+    }
+
+    pub fn race(&self) -> &NpcRace {
+        &self.race
+    }
+}
+
 // Enum for NPC sex
-#[derive(Serialize, Deserialize, Debug, Clone)]
-enum NpcSex {
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default, EnumIter)]
+pub enum NpcSex {
     Male,
     Female,
+    #[default]
     Unisex,
 }
 
 // Enum for NPC race
-#[derive(Serialize, Deserialize, Debug, Clone)]
-enum NpcRace {
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default, EnumIter)]
+pub enum NpcRace {
+    #[default]
     Human,
     Elf,
 }
 
 // Struct for representing a container
 #[derive(Serialize, Deserialize, Debug, Clone)]
-struct Container {
+pub struct Container {
     id: u32,
     container_type: ContainerType,
     town_id: u32,
@@ -231,10 +708,126 @@ struct Container {
     room_id: u32,
 }
 
+// Functions for Container
+impl Container {
+    pub fn id(&self) -> u32 {
+        self.id
+    }
+
+    pub fn container_type(&self) -> &ContainerType {
+        &self.container_type
+    }
+}
+
 // Enum for container types
 #[derive(Serialize, Deserialize, Debug, Clone)]
-enum ContainerType {
+pub enum ContainerType {
     Barrel,
     Crate,
     Chest,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn world_manager(seed: u64) -> WorldManager {
+        let mut world_manager = WorldManager::new();
+        world_manager.load_world().unwrap();
+        world_manager.world_seed = seed;
+
+        world_manager
+    }
+
+    // The bundled towns with a world.dot of our own, in a scratch world directory
+    fn world_manager_with_dot(name: &str, dot: &str) -> WorldManager {
+        let world_dir =
+            std::env::temp_dir().join(format!("cli-town-explorer-{}-{}", std::process::id(), name));
+        fs::create_dir_all(&world_dir).unwrap();
+        fs::copy(
+            Path::new(DEFAULT_WORLD_DIR).join("world.json"),
+            world_dir.join("world.json"),
+        )
+        .unwrap();
+        fs::write(world_dir.join("world.dot"), dot).unwrap();
+
+        let mut world_manager = WorldManager::new();
+        world_manager.world_dir = world_dir;
+
+        world_manager
+    }
+
+    #[test]
+    fn rejects_a_road_without_a_usable_label() {
+        for (name, attributes) in [
+            ("unlabelled", "[len=1]"),
+            ("mislabelled", "[label=\"far\"]"),
+        ] {
+            let mut world_manager = world_manager_with_dot(
+                name,
+                &format!(
+                    "graph Towns {{\n    \"Ash Burhwich\" -- \"Har Leahhampton\" {};\n}}\n",
+                    attributes
+                ),
+            );
+
+            assert_eq!(
+                world_manager.load_world().unwrap_err().to_string(),
+                "Invalid world.dot, line 2, column 5: the road from Ash Burhwich to Har Leahhampton needs a label like \"22 m / 110 gold\""
+            );
+
+            fs::remove_dir_all(&world_manager.world_dir).unwrap();
+        }
+    }
+
+    #[test]
+    fn towns_missing_from_the_dot_file_are_unreachable() {
+        let mut world_manager = world_manager_with_dot(
+            "missing-towns",
+            "graph Towns {\n    \"Ash Burhwich\" -- \"Har Leahhampton\" [label=\"10 m / 50 gold\"];\n}\n",
+        );
+        world_manager.load_world().unwrap();
+        fs::remove_dir_all(&world_manager.world_dir).unwrap();
+
+        let origin = String::from("Ash Burhwich");
+
+        assert_eq!(
+            world_manager
+                .plan_route(&origin, &String::from("Har Leahhampton"))
+                .unwrap()
+                .legs
+                .len(),
+            1
+        );
+        assert!(matches!(
+            world_manager.plan_route(&origin, &String::from("Higashi Yamamura")),
+            Err(super::super::journey::RouteError::Unreachable(_))
+        ));
+        assert!(world_manager
+            .town_names()
+            .iter()
+            .all(|town| world_manager.node_indices.contains_key(town)));
+    }
+
+    #[test]
+    fn the_same_seed_gives_the_same_start() {
+        for seed in [0, 1, 42, 0xDEAD_BEEF] {
+            let first = world_manager(seed);
+            let second = world_manager(seed);
+
+            assert_eq!(first.random_town(), second.random_town());
+            assert_eq!(first.new_player_id(), second.new_player_id());
+            assert!(first.town_names().contains(&first.random_town().unwrap()));
+            assert_ne!(first.new_player_id(), 0);
+        }
+    }
+
+    #[test]
+    fn different_seeds_pick_different_towns() {
+        let towns: std::collections::HashSet<String> = (0..64)
+            .filter_map(|seed| world_manager(seed).random_town())
+            .collect();
+
+        assert!(towns.len() > 1);
+    }
+}
@@ -0,0 +1,240 @@
+use std::collections::HashMap;
+
+// Consts for the spring layout, how many passes to make and how far apart unconnected towns sit
+const LAYOUT_ITERATIONS: usize = 300;
+const UNCONNECTED_SPACING: f64 = 1.5;
+
+// Struct for a road between two towns
+#[derive(Debug, Clone)]
+pub struct Road {
+    pub from: String,
+    pub to: String,
+    pub distance: u32,
+}
+
+// Struct for the world map, every town's position laid out from the roads between them
+pub struct WorldMap {
+    pub positions: HashMap<String, (f64, f64)>,
+    pub roads: Vec<Road>,
+}
+
+// Functions for World Map
+impl WorldMap {
+    // Create a new World Map, laying out towns so the distance between them matches the miles by road
+    // Every town is placed, including any with no roads to them
+    pub fn new(mut towns: Vec<String>, roads: Vec<Road>) -> Self {
+        towns.extend(
+            roads
+                .iter()
+                .flat_map(|road| [road.from.clone(), road.to.clone()]),
+        );
+        towns.sort();
+        towns.dedup();
+
+        let ideal = ideal_distances(&towns, &roads);
+        let points = spring_layout(&ideal);
+
+        Self {
+            positions: towns.into_iter().zip(points).collect(),
+            roads,
+        }
+    }
+
+    // The area the map covers, with room around the edges and extra on the right as town names are printed after their marker
+    pub fn bounds(&self) -> ([f64; 2], [f64; 2]) {
+        let (mut min_x, mut max_x) = (f64::MAX, f64::MIN);
+        let (mut min_y, mut max_y) = (f64::MAX, f64::MIN);
+
+        for &(x, y) in self.positions.values() {
+            min_x = min_x.min(x);
+            max_x = max_x.max(x);
+            min_y = min_y.min(y);
+            max_y = max_y.max(y);
+        }
+
+        if self.positions.is_empty() {
+            return ([0.0, 1.0], [0.0, 1.0]);
+        }
+
+        let margin_x = ((max_x - min_x) * 0.1).max(1.0);
+        let margin_y = ((max_y - min_y) * 0.1).max(1.0);
+
+        (
+            [min_x - margin_x, max_x + margin_x * 3.0],
+            [min_y - margin_y, max_y + margin_y],
+        )
+    }
+}
+
+// Shortest distance by road between every pair of towns, towns with no way between them are kept well apart
+fn ideal_distances(towns: &[String], roads: &[Road]) -> Vec<Vec<f64>> {
+    let count = towns.len();
+    let index = |name: &String| towns.iter().position(|town| town == name);
+
+    let mut distances = vec![vec![f64::INFINITY; count]; count];
+
+    for (i, row) in distances.iter_mut().enumerate() {
+        row[i] = 0.0;
+    }
+
+    for road in roads {
+        if let (Some(from), Some(to)) = (index(&road.from), index(&road.to)) {
+            let distance = (road.distance.max(1) as f64).min(distances[from][to]);
+
+            distances[from][to] = distance;
+            distances[to][from] = distance;
+        }
+    }
+
+    // Floyd-Warshall
+    for k in 0..count {
+        for i in 0..count {
+            for j in 0..count {
+                let through = distances[i][k] + distances[k][j];
+
+                if through < distances[i][j] {
+                    distances[i][j] = through;
+                }
+            }
+        }
+    }
+
+    let longest = distances
+        .iter()
+        .flatten()
+        .copied()
+        .filter(|distance| distance.is_finite())
+        .fold(1.0, f64::max);
+
+    for distance in distances.iter_mut().flatten() {
+        if !distance.is_finite() {
+            *distance = longest * UNCONNECTED_SPACING;
+        }
+    }
+
+    distances
+}
+
+// Lay out points so the distance between each pair is as close as possible to the ideal, like springs pulling them into place
+// Starts from a circle so the same world is always drawn the same way
+fn spring_layout(ideal: &[Vec<f64>]) -> Vec<(f64, f64)> {
+    let count = ideal.len();
+
+    let radius = ideal.iter().flatten().copied().fold(1.0, f64::max) / 2.0;
+
+    let mut points: Vec<(f64, f64)> = (0..count)
+        .map(|i| {
+            let angle = i as f64 / count.max(1) as f64 * std::f64::consts::TAU;
+
+            (radius * angle.cos(), radius * angle.sin())
+        })
+        .collect();
+
+    for _ in 0..LAYOUT_ITERATIONS {
+        for i in 0..count {
+            let (mut sum_x, mut sum_y, mut sum_weight) = (0.0, 0.0, 0.0);
+
+            for j in 0..count {
+                if i == j {
+                    continue;
+                }
+
+                let (dx, dy) = (points[i].0 - points[j].0, points[i].1 - points[j].1);
+                let length = (dx * dx + dy * dy).sqrt().max(0.001);
+
+                // Closer towns pull harder so nearby roads come out the right length
+                let weight = 1.0 / (ideal[i][j] * ideal[i][j]);
+
+                sum_x += weight * (points[j].0 + ideal[i][j] * dx / length);
+                sum_y += weight * (points[j].1 + ideal[i][j] * dy / length);
+                sum_weight += weight;
+            }
+
+            if sum_weight > 0.0 {
+                points[i] = (sum_x / sum_weight, sum_y / sum_weight);
+            }
+        }
+    }
+
+    points
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn road(from: &str, to: &str, distance: u32) -> Road {
+        Road {
+            from: from.to_string(),
+            to: to.to_string(),
+            distance,
+        }
+    }
+
+    fn apart(map: &WorldMap, a: &str, b: &str) -> f64 {
+        let (x1, y1) = map.positions[a];
+        let (x2, y2) = map.positions[b];
+
+        ((x1 - x2).powi(2) + (y1 - y2).powi(2)).sqrt()
+    }
+
+    fn towns(names: &[&str]) -> Vec<String> {
+        names.iter().map(|name| name.to_string()).collect()
+    }
+
+    #[test]
+    fn linked_towns_sit_about_their_road_distance_apart() {
+        let roads = vec![
+            road("Ashford", "Brook", 10),
+            road("Brook", "Crossing", 20),
+            road("Ashford", "Crossing", 25),
+            road("Crossing", "Dunmere", 15),
+        ];
+        let map = WorldMap::new(
+            towns(&["Ashford", "Brook", "Crossing", "Dunmere"]),
+            roads.clone(),
+        );
+
+        for road in &roads {
+            let distance = apart(&map, &road.from, &road.to);
+            let expected = road.distance as f64;
+
+            assert!(
+                (distance - expected).abs() <= expected * 0.2,
+                "{} to {} is {:.1} on the map, {} by road",
+                road.from,
+                road.to,
+                distance,
+                road.distance
+            );
+        }
+    }
+
+    #[test]
+    fn places_towns_with_no_roads_away_from_the_rest() {
+        let roads = vec![road("Ashford", "Brook", 10), road("Brook", "Crossing", 20)];
+        let map = WorldMap::new(
+            towns(&["Ashford", "Brook", "Crossing", "Far Hollow"]),
+            roads,
+        );
+
+        assert_eq!(map.positions.len(), 4);
+
+        for town in ["Ashford", "Brook", "Crossing"] {
+            assert!(apart(&map, town, "Far Hollow") > 20.0);
+        }
+
+        let (x_bounds, y_bounds) = map.bounds();
+        let (x, y) = map.positions["Far Hollow"];
+        assert!(x_bounds[0] < x && x < x_bounds[1]);
+        assert!(y_bounds[0] < y && y < y_bounds[1]);
+    }
+
+    #[test]
+    fn lays_out_towns_even_without_any_roads() {
+        let map = WorldMap::new(towns(&["Ashford", "Brook"]), Vec::new());
+
+        assert_eq!(map.positions.len(), 2);
+        assert!(apart(&map, "Ashford", "Brook") > 0.5);
+    }
+}
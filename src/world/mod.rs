@@ -1,3 +1,10 @@
+pub mod dot;
+pub mod journey;
+pub mod loot;
 pub mod manager;
+pub mod map;
+pub mod shop;
+pub mod tavern;
+pub mod temple;
 pub mod time;
 pub mod weather;
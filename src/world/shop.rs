@@ -0,0 +1,207 @@
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use serde::{Deserialize, Serialize};
+
+use crate::entities::inventory::ItemStack;
+use crate::entities::item::{Item, ItemKind};
+
+// Consts for shop behaviour
+const RESTOCK_DAYS: u32 = 3;
+const MAX_REPUTATION: i32 = 50;
+
+// Salts for seeding stock, distinct from the loot and creation salts so a shop's stock doesn't
+// follow the contents of the container sharing its id
+const BUILDING_SALT: u64 = 0xBF58_476D_1CE4_E5B9;
+const RESTOCK_SALT: u64 = 0xC2B2_AE3D_27D4_EB4F;
+
+// Struct for a shop's stock
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ShopStock {
+    pub restock_cycle: u32,
+    pub stacks: Vec<ItemStack>,
+}
+
+// Functions for Shop Stock
+impl ShopStock {
+    // Generate a shop's stock for the restock cycle the given day falls in
+    pub fn generate(building_id: u32, world_seed: u64, day: u32) -> Self {
+        let restock_cycle = restock_cycle(day);
+
+        let mut rng = StdRng::seed_from_u64(
+            world_seed
+                ^ (building_id as u64).wrapping_mul(BUILDING_SALT)
+                ^ (restock_cycle as u64).wrapping_mul(RESTOCK_SALT),
+        );
+
+        // Shops sell anything except treasure
+        let wares: Vec<Item> = crate::entities::item::catalogue()
+            .into_iter()
+            .filter(|item| item.kind != ItemKind::Treasure)
+            .collect();
+
+        let mut stacks: Vec<ItemStack> = Vec::new();
+
+        for _ in 0..rng.random_range(5..=8) {
+            let item = wares[rng.random_range(0..wares.len())].clone();
+
+            if stacks.iter().any(|stack| stack.item.id == item.id) {
+                continue;
+            }
+
+            let quantity = rng.random_range(1..=item.max_stack.min(5));
+            stacks.push(ItemStack { item, quantity });
+        }
+
+        Self {
+            restock_cycle,
+            stacks,
+        }
+    }
+
+    // Check whether the shop is due new stock on the given day
+    pub fn needs_restock(&self, day: u32) -> bool {
+        restock_cycle(day) != self.restock_cycle
+    }
+
+    // Put an item the player sold on the shelves
+    pub fn add(&mut self, item: Item, quantity: u32) {
+        match self
+            .stacks
+            .iter_mut()
+            .find(|stack| stack.item.id == item.id)
+        {
+            Some(stack) => stack.quantity += quantity,
+            None => self.stacks.push(ItemStack { item, quantity }),
+        }
+    }
+}
+
+// Which restock cycle a day falls in
+fn restock_cycle(day: u32) -> u32 {
+    day.saturating_sub(1) / RESTOCK_DAYS
+}
+
+// Price multiplier for a town, some towns are simply dearer than others
+pub fn town_modifier(town_id: u32) -> f32 {
+    let spread = ((town_id as u64).wrapping_mul(2_654_435_761) % 41) as f32;
+
+    0.85 + spread / 100.0
+}
+
+// Price multiplier for the player's reputation, up to 20% off at best and 20% on at worst
+fn reputation_modifier(reputation: i32) -> f32 {
+    1.0 - reputation.clamp(-MAX_REPUTATION, MAX_REPUTATION) as f32 / (MAX_REPUTATION as f32 * 5.0)
+}
+
+// Price the shop charges the player for an item
+pub fn buy_price(item: &Item, town_id: u32, reputation: i32) -> u32 {
+    let price = item.value as f32 * town_modifier(town_id) * reputation_modifier(reputation);
+
+    (price.round() as u32).max(1)
+}
+
+// Price the shop pays the player for an item
+pub fn sell_price(item: &Item, town_id: u32, reputation: i32) -> u32 {
+    let price =
+        item.value as f32 * 0.4 * town_modifier(town_id) * (2.0 - reputation_modifier(reputation));
+
+    price.round() as u32
+}
+
+// Reputation gained in a town for each purchase
+pub fn reputation_gain(reputation: i32) -> i32 {
+    if reputation < MAX_REPUTATION {
+        1
+    } else {
+        0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ids(stock: &ShopStock) -> Vec<(u32, u32)> {
+        stock
+            .stacks
+            .iter()
+            .map(|stack| (stack.item.id, stack.quantity))
+            .collect()
+    }
+
+    #[test]
+    fn stock_stays_the_same_within_a_restock_cycle() {
+        let first = ShopStock::generate(12, 99, 4);
+
+        for day in 4..4 + RESTOCK_DAYS {
+            let stock = ShopStock::generate(12, 99, day);
+
+            assert_eq!(stock.restock_cycle, first.restock_cycle);
+            assert_eq!(ids(&stock), ids(&first));
+            assert!(!first.needs_restock(day));
+        }
+
+        assert!(first.needs_restock(4 + RESTOCK_DAYS));
+        assert!(first.needs_restock(3));
+    }
+
+    #[test]
+    fn stock_changes_across_restock_cycles() {
+        let cycles: Vec<Vec<(u32, u32)>> = (0..10)
+            .map(|cycle| ids(&ShopStock::generate(12, 99, 1 + cycle * RESTOCK_DAYS)))
+            .collect();
+
+        for pair in cycles.windows(2) {
+            assert_ne!(pair[0], pair[1]);
+        }
+
+        for stock in &cycles {
+            assert!(!stock.is_empty());
+        }
+    }
+
+    #[test]
+    fn stock_doesnt_follow_container_loot() {
+        use crate::world::manager::{BuildingType, ContainerType};
+
+        // With a shared salt the same id and seed drew from the same random numbers
+        let matching = (1..50)
+            .filter(|&id| {
+                let stock = ShopStock::generate(id, 7, 1);
+                let loot =
+                    crate::world::loot::generate(id, &ContainerType::Crate, &BuildingType::Shop, 7);
+
+                stock.stacks.first().map(|stack| stack.item.id)
+                    == loot.first().map(|stack| stack.item.id)
+            })
+            .count();
+
+        assert!(
+            matching < 10,
+            "{} of 49 shops opened with their crate's loot",
+            matching
+        );
+    }
+
+    #[test]
+    fn never_buys_for_less_than_it_sells() {
+        for item in crate::entities::item::catalogue() {
+            for town_id in 0..100 {
+                for reputation in -2 * MAX_REPUTATION..=2 * MAX_REPUTATION {
+                    let buy = buy_price(&item, town_id, reputation);
+                    let sell = sell_price(&item, town_id, reputation);
+
+                    assert!(
+                        buy >= sell,
+                        "{} in town {} at reputation {} buys for {} and sells for {}",
+                        item.name,
+                        town_id,
+                        reputation,
+                        buy,
+                        sell
+                    );
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,155 @@
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+
+use super::manager::{BuildingType, WorldManager};
+
+// Consts for tavern behaviour
+pub const REST_COST: u32 = 15;
+const RUMOUR_COUNT: usize = 3;
+
+// Gather today's rumours from a tavern, always the same for the same tavern, day and world seed
+pub fn rumours(world_manager: &WorldManager, building_id: u32, day: u32) -> Vec<String> {
+    let (Some(world), Some(player)) = (world_manager.world.as_ref(), world_manager.player.as_ref())
+    else {
+        return Vec::new();
+    };
+
+    let mut rng = StdRng::seed_from_u64(
+        world_manager.world_seed
+            ^ (building_id as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15)
+            ^ (day as u64).wrapping_mul(0xC2B2_AE3D_27D4_EB4F),
+    );
+
+    let mut candidates: Vec<String> = Vec::new();
+
+    // Roads leading out of town
+    for (neighbour, distance, cost) in world_manager.neighbours(&player.town_name) {
+        candidates.push(match rng.random_range(0..2) {
+            0 => format!(
+                "A carter swears the road to {} is {} miles, and the coach wants {} gold for it.",
+                neighbour, distance, cost
+            ),
+            _ => format!(
+                "Folk from {} pass through all the time, it's only {} miles off.",
+                neighbour, distance
+            ),
+        });
+    }
+
+    // Gossip about other towns, in id order as the towns map has no order of its own
+    let mut towns: Vec<&super::manager::Town> = world.towns.values().collect();
+    towns.sort_by_key(|town| town.id());
+
+    for town in towns {
+        if town.name == player.town_name {
+            continue;
+        }
+
+        let modifier = super::shop::town_modifier(town.id());
+        candidates.push(if modifier > 1.1 {
+            format!(
+                "Don't buy anything in {}, the shopkeepers there will fleece you.",
+                town.name
+            )
+        } else if modifier < 0.95 {
+            format!("Goods come cheap in {}, if you can get there.", town.name)
+        } else {
+            format!("Prices in {} are fair enough, so they say.", town.name)
+        });
+
+        let count = |building_type: fn(&BuildingType) -> bool| {
+            town.buildings()
+                .iter()
+                .filter(|building| building_type(building.building_type()))
+                .count()
+        };
+
+        let temples = count(|building_type| matches!(building_type, BuildingType::Temple));
+        let taverns = count(|building_type| matches!(building_type, BuildingType::Tavern));
+
+        if temples > 0 {
+            candidates.push(format!(
+                "Pilgrims head to {} for its {} temple{}.",
+                town.name,
+                temples,
+                if temples == 1 { "" } else { "s" }
+            ));
+        }
+
+        if taverns == 0 {
+            candidates.push(format!(
+                "If you're ever in {}, bring your own ale. There's not a tavern in the place.",
+                town.name
+            ));
+        }
+    }
+
+    candidates.shuffle(&mut rng);
+    candidates.truncate(RUMOUR_COUNT);
+
+    candidates
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Load the bundled world fresh, as a restart or reload would
+    fn world_manager(seed: u64) -> WorldManager {
+        let mut world_manager = WorldManager::new();
+        world_manager.load_world().unwrap();
+        world_manager.world_seed = seed;
+
+        let town_name = world_manager.town_names()[0].clone();
+        world_manager.player = Some(crate::entities::player::Player::new(
+            1,
+            "Tester".into(),
+            town_name,
+            100,
+            Default::default(),
+        ));
+
+        world_manager
+    }
+
+    fn tavern_id(world_manager: &WorldManager) -> u32 {
+        world_manager
+            .current_town()
+            .and_then(|town| {
+                town.buildings()
+                    .iter()
+                    .find(|building| matches!(building.building_type(), BuildingType::Tavern))
+            })
+            .map(|building| building.id())
+            .unwrap()
+    }
+
+    #[test]
+    fn rumours_are_the_same_across_separately_loaded_worlds() {
+        for seed in [1, 42, 0xDEAD_BEEF] {
+            let first = world_manager(seed);
+            let second = world_manager(seed);
+            let tavern = tavern_id(&first);
+
+            for day in 1..=5 {
+                let rumours = rumours(&first, tavern, day);
+
+                assert_eq!(rumours.len(), RUMOUR_COUNT);
+                assert_eq!(rumours, super::rumours(&second, tavern, day));
+            }
+        }
+    }
+
+    #[test]
+    fn rumours_change_from_day_to_day() {
+        let world_manager = world_manager(7);
+        let tavern = tavern_id(&world_manager);
+
+        let days: Vec<Vec<String>> = (1..=5)
+            .map(|day| rumours(&world_manager, tavern, day))
+            .collect();
+
+        assert!(days.windows(2).any(|pair| pair[0] != pair[1]));
+    }
+}
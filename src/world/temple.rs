@@ -0,0 +1,33 @@
+use crate::entities::effects::EffectKind;
+
+// Struct for something a temple offers the player
+pub struct Service {
+    pub name: &'static str,
+    pub cost: u32,
+    pub kind: ServiceKind,
+}
+
+// Enum for what a service does
+pub enum ServiceKind {
+    Bless(EffectKind, u32),
+    Heal,
+}
+
+// Const for the services every temple offers, blessing durations are in ticks
+pub const SERVICES: [Service; 3] = [
+    Service {
+        name: "Blessing of Fortune",
+        cost: 40,
+        kind: ServiceKind::Bless(EffectKind::Fortune, crate::world::time::TICKS_PER_DAY),
+    },
+    Service {
+        name: "Blessing of the Road",
+        cost: 30,
+        kind: ServiceKind::Bless(EffectKind::Wayfarer, crate::world::time::TICKS_PER_DAY * 2),
+    },
+    Service {
+        name: "Healing",
+        cost: 20,
+        kind: ServiceKind::Heal,
+    },
+];
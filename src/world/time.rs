@@ -4,6 +4,9 @@ use std::sync::{Arc, RwLock};
 use std::thread;
 use std::time::{Duration, Instant};
 
+// Number of ticks in one day (e.g., 900 ticks for 15 minutes)
+pub const TICKS_PER_DAY: u32 = 900;
+
 // Struct for Time Manager
 pub struct TimeManager {
     pub time_arc_rwlock: Option<Arc<RwLock<GameTime>>>,
@@ -32,28 +35,8 @@ impl TimeManager {
             // Total real-world time for one in-game day (15 minutes)
             let real_time_per_day = Duration::from_secs(15 * 60);
 
-            // Number of ticks in one day (e.g., 900 ticks for 15 minutes)
-            let ticks_per_day = 900;
-
             // Duration of one tick in real-world time
-            let tick_duration = real_time_per_day / ticks_per_day;
-
-            // Day/Night cycle
-            let dawn_ticks = (ticks_per_day as f64 * 0.25) as u32;
-            let day_ticks = (ticks_per_day as f64 * 0.5) as u32;
-            let dusk_ticks = (ticks_per_day as f64 * 0.75) as u32;
-            let _night_ticks = (ticks_per_day as f64 * 1.0) as u32;
-
-            // Time tracking
-            let mut current_tick = 0;
-            let mut current_day = 1;
-            let mut current_phase = Phase::Dawn;
-
-            if let Ok(game_time_unwrapped) = game_time_arc_clone.read() {
-                current_tick = game_time_unwrapped.tick;
-                current_day = game_time_unwrapped.day;
-                current_phase = game_time_unwrapped.phase.clone();
-            }
+            let tick_duration = real_time_per_day / TICKS_PER_DAY;
 
             let mut accumulated_time = Duration::ZERO;
             let mut last_time = Instant::now();
@@ -72,39 +55,19 @@ impl TimeManager {
                 // Accumulate elapsed time
                 accumulated_time += delta;
 
-                // We only want to update GameTime when the time has changed
-                let mut time_updated = false;
-
-                // Process a tick if enough real world time has passed
+                // Count the ticks that enough real world time has passed for
+                let mut elapsed_ticks = 0;
                 while accumulated_time >= tick_duration {
                     accumulated_time -= tick_duration;
-                    current_tick += 1;
-                    time_updated = true;
-
-                    // Increment current day once ticks per day limit reached, reset ticks to 0
-                    if current_tick == ticks_per_day {
-                        current_day += 1;
-                        current_tick = 0;
-                    }
-
-                    // Determine current day/night phase
-                    if current_tick <= dawn_ticks {
-                        current_phase = Phase::Dawn
-                    } else if current_tick > dawn_ticks && current_tick <= day_ticks {
-                        current_phase = Phase::Day
-                    } else if current_tick > day_ticks && current_tick <= dusk_ticks {
-                        current_phase = Phase::Dusk
-                    } else {
-                        current_phase = Phase::Night
-                    }
+                    elapsed_ticks += 1;
                 }
 
-                // Only update GameTime if something changed
-                if time_updated {
-                    let mut time = game_time_arc_clone.write().unwrap();
-                    time.tick = current_tick;
-                    time.day = current_day;
-                    time.phase = current_phase.clone();
+                // Only update GameTime when the time has changed
+                // Advancing under the lock keeps any change made by TimeManager::advance
+                if elapsed_ticks > 0 {
+                    if let Ok(mut time) = game_time_arc_clone.write() {
+                        time.advance(elapsed_ticks);
+                    }
                 }
 
                 // Tiny sleep to prevent excessive CPU usage
@@ -115,6 +78,22 @@ impl TimeManager {
         self.time_arc_rwlock = Some(game_time);
     }
 
+    // Get a copy of the current Game Time
+    pub fn current(&self) -> Option<GameTime> {
+        self.time_arc_rwlock
+            .as_ref()
+            .and_then(|game_time| game_time.read().ok().map(|t| t.clone()))
+    }
+
+    // Move time forward by a number of ticks, e.g. for travelling or resting
+    // Returns the new Game Time
+    pub fn advance(&self, ticks: u32) -> Option<GameTime> {
+        let mut time = self.time_arc_rwlock.as_ref()?.write().ok()?;
+        time.advance(ticks);
+
+        Some(time.clone())
+    }
+
     // Stop the time thread
     pub fn stop(&self) {
         self.shutdown_flag.store(true, Ordering::Relaxed);
@@ -139,13 +118,47 @@ impl GameTime {
             phase: Phase::Dawn,
         }
     }
+
+    // Move forward by a number of ticks, rolling over into new days
+    pub fn advance(&mut self, ticks: u32) {
+        let total_ticks = self.tick + ticks;
+
+        self.day += total_ticks / TICKS_PER_DAY;
+        self.tick = total_ticks % TICKS_PER_DAY;
+        self.phase = Phase::from_tick(self.tick);
+    }
+
+    // Number of ticks until dawn on the next day
+    pub fn ticks_until_next_dawn(&self) -> u32 {
+        TICKS_PER_DAY - self.tick
+    }
 }
 
 // Enum for day/night phases
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum Phase {
     Dawn,
     Day,
     Dusk,
     Night,
 }
+
+// Functions for Phase
+impl Phase {
+    // Determine the day/night phase for a tick of the day
+    pub fn from_tick(tick: u32) -> Self {
+        let dawn_ticks = (TICKS_PER_DAY as f64 * 0.25) as u32;
+        let day_ticks = (TICKS_PER_DAY as f64 * 0.5) as u32;
+        let dusk_ticks = (TICKS_PER_DAY as f64 * 0.75) as u32;
+
+        if tick <= dawn_ticks {
+            Phase::Dawn
+        } else if tick <= day_ticks {
+            Phase::Day
+        } else if tick <= dusk_ticks {
+            Phase::Dusk
+        } else {
+            Phase::Night
+        }
+    }
+}
@@ -1,4 +1,3 @@
-use rand::Rng;
 use serde::{Deserialize, Serialize};
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Arc, RwLock};
@@ -24,7 +23,7 @@ impl WeatherManager {
     }
 
     // Start weather, spawns in a new thread
-    pub fn start(&mut self, initial_game_weather: GameWeather) {
+    pub fn start(&mut self, initial_game_weather: GameWeather, mut dice: crate::core::dice::Dice) {
         let game_weather = Arc::new(RwLock::new(initial_game_weather));
         let game_weather_arc_clone = Arc::clone(&game_weather);
 
@@ -38,18 +37,11 @@ impl WeatherManager {
             }
 
             // Roll to see if weather changes
-            if roll_dice(100) < 5 {
+            if dice.die(100) < 5 {
                 let mut weather = game_weather_arc_clone.write().unwrap();
 
                 // Roll to decide weather type
-                match roll_dice(WeatherType::COUNT as u32) {
-                    0 => weather.weather_type = WeatherType::Sunny,
-                    1 => weather.weather_type = WeatherType::Raining,
-                    2 => weather.weather_type = WeatherType::Windy,
-                    3 => weather.weather_type = WeatherType::Stormy,
-                    4 => weather.weather_type = WeatherType::Snowing,
-                    _ => weather.weather_type = WeatherType::Sunny,
-                }
+                weather.weather_type = WeatherType::roll(&mut dice);
             }
 
             // 10 second sleep to prevent excessive CPU usage
@@ -65,12 +57,6 @@ impl WeatherManager {
     }
 }
 
-// Function for dice rolls
-fn roll_dice(dice_size: u32) -> u32 {
-    let mut rng = rand::rng();
-    rng.random_range(1..=dice_size)
-}
-
 // Struct for Game Weather
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GameWeather {
@@ -88,7 +74,7 @@ impl GameWeather {
 }
 
 // Enum for weather types
-#[derive(Debug, Clone, EnumCount, EnumIter, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, EnumCount, EnumIter, Serialize, Deserialize)]
 pub enum WeatherType {
     Sunny,
     Raining,
@@ -96,3 +82,38 @@ pub enum WeatherType {
     Stormy,
     Snowing,
 }
+
+// Functions for Weather Type
+impl WeatherType {
+    // Roll a weather type, one face of the die for each
+    pub fn roll(dice: &mut crate::core::dice::Dice) -> Self {
+        match dice.die(WeatherType::COUNT as u32) {
+            1 => WeatherType::Sunny,
+            2 => WeatherType::Raining,
+            3 => WeatherType::Windy,
+            4 => WeatherType::Stormy,
+            _ => WeatherType::Snowing,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use strum::IntoEnumIterator;
+
+    #[test]
+    fn every_weather_type_can_be_rolled() {
+        let mut dice = crate::core::dice::Dice::new(3);
+        let rolled: std::collections::HashSet<WeatherType> =
+            (0..200).map(|_| WeatherType::roll(&mut dice)).collect();
+
+        for weather_type in WeatherType::iter() {
+            assert!(
+                rolled.contains(&weather_type),
+                "never rolled {:?}",
+                weather_type
+            );
+        }
+    }
+}